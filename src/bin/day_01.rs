@@ -3,19 +3,34 @@
 /// Code for the day 01 of the Advent of Code challenge year 2024
 ///
 // Imports  ==============================================================================  Imports
+use aoc_2024::{parse_int_columns, ColumnsParseError};
 use std::{collections::HashMap, str::FromStr};
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_01.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(1));
 
 #[derive(Debug)]
 struct Data {
-    left_values: Vec<i32>,
-    right_values: Vec<i32>,
+    // One vector per column, every line having a value in each column
+    columns: Vec<Vec<i32>>,
 }
 
-// Since the input is a list of '   ' separated values, we can split the input
-// and parse each value to an integer.
+impl Data {
+    fn column(&self, index: usize) -> &[i32] {
+        self.columns.get(index).map_or(&[], Vec::as_slice)
+    }
+
+    fn left(&self) -> &[i32] {
+        self.column(0)
+    }
+
+    fn right(&self) -> &[i32] {
+        self.column(1)
+    }
+}
+
+// Since the input is a list of whitespace separated values, we can split each line
+// and parse each value to an integer, then transpose the lines into columns.
 /**
 Ex:
 15244   50562
@@ -24,42 +39,89 @@ Ex:
 63271   60643
 */
 impl FromStr for Data {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut left_values = Vec::new();
-        let mut right_values = Vec::new();
-
-        for line in s.lines() {
-            let mut values = line.split_whitespace();
-            left_values.push(values.next().unwrap().parse().unwrap());
-            right_values.push(values.next().unwrap().parse().unwrap());
-        }
-
-        Ok(Data {
-            left_values,
-            right_values,
-        })
+        let columns = parse_int_columns(s).map_err(|e| match e {
+            ColumnsParseError::InvalidInt(e) => e.to_string(),
+            ColumnsParseError::RaggedRow { row, len, width } => format!(
+                "Ragged input: row {} has {len} values instead of {width}",
+                row + 1
+            ),
+        })?;
+
+        Ok(Data { columns })
     }
 }
 // Functions  =========================================================================== Functions
-pub fn response_part_1() {
-    println!("Day 01 - Part 1");
-
-    let start = std::time::Instant::now();
+///
+/// # `paired_distances`
+/// Pair the smallest left value with the smallest right one, the second smallest with
+/// the second smallest and so on.
+///
+/// ## Arguments
+/// * `left` - The left list
+/// * `right` - The right list
+///
+/// ## Returns
+/// * `Vec<(i32, i32, i32)>` - The `(left, right, distance)` pairs, in sorted order
+fn paired_distances(left: &[i32], right: &[i32]) -> Vec<(i32, i32, i32)> {
+    let mut left = left.to_vec();
+    let mut right = right.to_vec();
+
+    left.sort();
+    right.sort();
+
+    left.into_iter()
+        .zip(right)
+        .map(|(a, b)| (a, b, (a - b).abs()))
+        .collect()
+}
 
-    let data: Data = INPUT.parse().unwrap();
-    let mut left_values = data.left_values;
-    let mut right_values = data.right_values;
+///
+/// # `similarity_score`
+/// Sum of each left value multiplied by its number of occurrences in the right list.
+///
+/// ## Arguments
+/// * `left` - The left list
+/// * `right` - The right list
+///
+/// ## Returns
+/// * `u64` - The similarity score
+fn similarity_score(left: &[i32], right: &[i32]) -> u64 {
+    let mut right_counts: HashMap<i32, u64> = HashMap::with_capacity(right.len());
+    for &value in right {
+        *right_counts.entry(value).or_insert(0) += 1;
+    }
 
-    left_values.sort();
-    right_values.sort();
+    left.iter()
+        .map(|value| *value as u64 * right_counts.get(value).unwrap_or(&0))
+        .sum()
+}
 
-    let sum: i32 = left_values
+///
+/// # `part_1`
+/// Total distance between the sorted left and right lists.
+fn part_1(data: &Data) -> i32 {
+    paired_distances(data.left(), data.right())
         .iter()
-        .zip(right_values.iter())
-        .map(|(a, b)| (a - b).abs())
-        .sum();
+        .map(|&(_, _, distance)| distance)
+        .sum()
+}
+
+///
+/// # `part_2`
+/// Similarity score of the left and right lists.
+fn part_2(data: &Data) -> u64 {
+    similarity_score(data.left(), data.right())
+}
+
+fn response_part_1(data: &Data) {
+    println!("Day 01 - Part 1");
+
+    let start = std::time::Instant::now();
+
+    let sum = part_1(data);
 
     let duration = start.elapsed();
 
@@ -67,24 +129,12 @@ pub fn response_part_1() {
     println!("Duration: {duration:?}\n");
 }
 
-pub fn response_part_2() {
+fn response_part_2(data: &Data) {
     println!("Day 01 - Part 2");
 
     let start = std::time::Instant::now();
 
-    let data: Data = INPUT.parse().unwrap();
-    let left_values = data.left_values;
-    let right_values = data.right_values;
-
-    let mut right_values_count: HashMap<i32, u32> = std::collections::HashMap::new();
-    for value in right_values.iter() {
-        *right_values_count.entry(*value).or_insert(0) += 1;
-    }
-
-    let sum: u32 = left_values
-        .iter()
-        .map(|value| *value as u32 * right_values_count.get(value).unwrap_or(&0))
-        .sum();
+    let sum = part_2(data);
 
     let duration = start.elapsed();
 
@@ -93,8 +143,10 @@ pub fn response_part_2() {
 }
 
 fn main() {
-    response_part_1();
-    response_part_2();
+    let data: Data = INPUT.parse().unwrap();
+
+    response_part_1(&data);
+    response_part_2(&data);
 }
 
 // Tests ==================================================================================== Tests
@@ -107,8 +159,8 @@ mod tests {
         let input = "15244   50562\n81245   49036";
         let data = input.parse::<Data>().unwrap();
 
-        assert_eq!(data.left_values, vec![15244, 81245]);
-        assert_eq!(data.right_values, vec![50562, 49036]);
+        assert_eq!(data.left(), vec![15244, 81245]);
+        assert_eq!(data.right(), vec![50562, 49036]);
     }
 
     #[test]
@@ -116,16 +168,16 @@ mod tests {
         let input = "123     456\n789   101112";
         let data = input.parse::<Data>().unwrap();
 
-        assert_eq!(data.left_values, vec![123, 789]);
-        assert_eq!(data.right_values, vec![456, 101112]);
+        assert_eq!(data.left(), vec![123, 789]);
+        assert_eq!(data.right(), vec![456, 101112]);
     }
 
     #[test]
     fn test_part1_calculation() {
         let input = "100   150\n200   175\n300   250";
         let data = input.parse::<Data>().unwrap();
-        let mut left_values = data.left_values;
-        let mut right_values = data.right_values;
+        let mut left_values = data.left().to_vec();
+        let mut right_values = data.right().to_vec();
 
         left_values.sort();
         right_values.sort();
@@ -145,12 +197,12 @@ mod tests {
         let data = input.parse::<Data>().unwrap();
 
         let mut right_values_count: HashMap<i32, u32> = HashMap::new();
-        for value in data.right_values.iter() {
+        for value in data.right().iter() {
             *right_values_count.entry(*value).or_insert(0) += 1;
         }
 
         let sum: u32 = data
-            .left_values
+            .left()
             .iter()
             .map(|value| *value as u32 * right_values_count.get(value).unwrap_or(&0))
             .sum();
@@ -164,12 +216,12 @@ mod tests {
         let data = input.parse::<Data>().unwrap();
 
         let mut right_values_count: HashMap<i32, u32> = HashMap::new();
-        for value in data.right_values.iter() {
+        for value in data.right().iter() {
             *right_values_count.entry(*value).or_insert(0) += 1;
         }
 
         let sum: u32 = data
-            .left_values
+            .left()
             .iter()
             .map(|value| *value as u32 * right_values_count.get(value).unwrap_or(&0))
             .sum();
@@ -182,7 +234,76 @@ mod tests {
         let input = "";
         let data = input.parse::<Data>().unwrap();
 
-        assert!(data.left_values.is_empty());
-        assert!(data.right_values.is_empty());
+        assert!(data.left().is_empty());
+        assert!(data.right().is_empty());
+    }
+
+    #[test]
+    fn test_parse_three_columns() {
+        let input = "1   2   3\n4   5   6\n";
+        let data = input.parse::<Data>().unwrap();
+
+        assert_eq!(data.columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(data.left(), [1, 4]);
+        assert_eq!(data.right(), [2, 5]);
+    }
+
+    #[test]
+    fn test_parse_ragged_input() {
+        let input = "1   2\n3\n4   5";
+
+        assert_eq!(
+            input.parse::<Data>().unwrap_err(),
+            "Ragged input: row 2 has 1 values instead of 2"
+        );
+        assert!("1   x".parse::<Data>().is_err());
+    }
+
+    #[test]
+    fn test_similarity_score() {
+        let data = "100   100\n200   200\n100   300\n200   100"
+            .parse::<Data>()
+            .unwrap();
+
+        assert_eq!(similarity_score(data.left(), data.right()), 800);
+        assert_eq!(similarity_score(&[3, 4, 2], &[]), 0);
+        assert_eq!(
+            similarity_score(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]),
+            31
+        );
+    }
+
+    #[test]
+    fn test_paired_distances() {
+        let pairs = paired_distances(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+
+        assert_eq!(
+            pairs,
+            vec![
+                (1, 3, 2),
+                (2, 3, 1),
+                (3, 3, 0),
+                (3, 4, 1),
+                (3, 5, 2),
+                (4, 9, 5)
+            ]
+        );
+        assert_eq!(pairs.iter().map(|&(_, _, d)| d).sum::<i32>(), 11);
+    }
+
+    #[test]
+    fn test_shared_data_matches_parts() {
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3";
+
+        let part_1_result = part_1(&input.parse::<Data>().unwrap());
+        let part_2_result = part_2(&input.parse::<Data>().unwrap());
+
+        // Both parts sharing a single parsed input, as in `main`
+        let data = input.parse::<Data>().unwrap();
+        assert_eq!(
+            (part_1(&data), part_2(&data)),
+            (part_1_result, part_2_result)
+        );
+        assert_eq!((part_1_result, part_2_result), (11, 31));
     }
 }
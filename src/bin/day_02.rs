@@ -3,11 +3,16 @@
 /// Code for the day 02 of the Advent of Code challenge year 2024
 ///
 // Imports  ==============================================================================  Imports
+use aoc_2024::parse_int_rows;
+use rayon::prelude::*;
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_02.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(2));
+const MIN_LEVEL_DIFF: i32 = 1;
 const MAX_LEVEL_DIFF: i32 = 3;
+// Passing it checks the readings of part 2 on a single core
+const SEQUENTIAL_FLAG: &str = "--sequential";
 
 ///
 /// # ReactorReport
@@ -29,35 +34,12 @@ impl FromStr for ReactorReport {
     /// ## Returns
     /// * `Result<Self, Self::Err>` - Parsed reactor report or error
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let readings = s
-            .lines()
-            .map(|line| {
-                line.split_whitespace()
-                    .map(|n| n.parse().unwrap())
-                    .collect()
-            })
-            .collect();
+        let readings = parse_int_rows(s).map_err(|_| ())?;
 
         Ok(ReactorReport { readings })
     }
 }
 
-///
-/// # is_monotonic
-/// Checks if a sequence of levels is strictly increasing or decreasing
-///
-/// ## Arguments
-/// * `levels` - Vector of reactor levels to check
-///
-/// ## Returns
-/// * `bool` - True if levels are monotonic
-fn is_monotonic(levels: &[i32]) -> bool {
-    let increasing = levels.windows(2).all(|w| w[1] > w[0]);
-    let decreasing = levels.windows(2).all(|w| w[1] < w[0]);
-
-    increasing || decreasing
-}
-
 impl ReactorReport {
     ///
     /// # is_reading_safe
@@ -71,22 +53,31 @@ impl ReactorReport {
     /// ## Returns
     /// * `bool` - True if the reading is safe
     fn is_reading_safe(&self, levels: &[i32]) -> bool {
+        self.first_violation(levels).is_none()
+    }
+
+    ///
+    /// # first_violation
+    /// Finds the first level breaking the safety rules, the direction of the reading
+    /// being set by its first two levels
+    ///
+    /// ## Arguments
+    /// * `levels` - Vector of reactor levels to check
+    ///
+    /// ## Returns
+    /// * `Option<usize>` - Index of the first level too far from, too close to or in the
+    ///   wrong direction from the previous one, `None` if the reading is safe
+    fn first_violation(&self, levels: &[i32]) -> Option<usize> {
         if levels.len() < 2 {
-            return true;
+            return None;
         }
 
-        // Check if differences are valid (between 1 and 3)
-        let valid_differences = levels.windows(2).all(|w| {
-            let diff = (w[1] - w[0]).abs();
-            diff <= MAX_LEVEL_DIFF // Changed: Only check upper bound
-        });
+        let direction = (levels[1] - levels[0]).signum();
 
-        if !valid_differences {
-            return false;
-        }
-
-        // Then check if sequence is monotonic
-        is_monotonic(levels)
+        levels
+            .windows(2)
+            .position(|w| !(MIN_LEVEL_DIFF..=MAX_LEVEL_DIFF).contains(&((w[1] - w[0]) * direction)))
+            .map(|i| i + 1)
     }
 
     ///
@@ -99,21 +90,48 @@ impl ReactorReport {
     /// ## Returns
     /// * `bool` - True if the reading can be made safe
     fn is_reading_safe_with_dampener(&self, levels: &[i32]) -> bool {
-        if levels.len() < 2 {
-            return true;
-        }
+        self.is_reading_safe_with_k_removals(levels, 1)
+    }
 
-        // Check if already safe
-        if self.is_reading_safe(levels) {
+    ///
+    /// # is_reading_safe_with_k_removals
+    /// Checks if a reading can be made safe by removing up to `k` levels.
+    /// Instead of trying every subset of removed levels, finds the longest safe
+    /// subsequence in each direction (O(n²)), the reading being safe if the levels
+    /// outside of it are at most `k`.
+    ///
+    /// ## Arguments
+    /// * `levels` - Vector of reactor levels to check
+    /// * `k` - Maximum number of levels to remove
+    ///
+    /// ## Returns
+    /// * `bool` - True if the reading can be made safe
+    fn is_reading_safe_with_k_removals(&self, levels: &[i32], k: usize) -> bool {
+        // A reading of at most `k + 1` levels can always be cut down to a single level
+        if levels.len() <= k + 1 {
             return true;
         }
 
-        // Try removing each element and check if resulting sequence is safe
-        (0..levels.len()).any(|i| {
-            let mut modified = levels.to_vec();
-            modified.remove(i);
-            self.is_reading_safe(&modified)
-        })
+        let longest_safe = |direction: i32| {
+            // longest[i]: length of the longest safe subsequence ending at level i
+            let mut longest = vec![1; levels.len()];
+
+            for i in 1..levels.len() {
+                for j in 0..i {
+                    let diff = (levels[i] - levels[j]) * direction;
+
+                    if (MIN_LEVEL_DIFF..=MAX_LEVEL_DIFF).contains(&diff) {
+                        longest[i] = longest[i].max(longest[j] + 1);
+                    }
+                }
+            }
+
+            longest.into_iter().max().unwrap_or(0)
+        };
+
+        let kept = longest_safe(1).max(longest_safe(-1));
+
+        levels.len() - kept <= k
     }
 
     /// Count safe readings without Problem Dampener
@@ -131,6 +149,34 @@ impl ReactorReport {
             .filter(|reading| self.is_reading_safe_with_dampener(reading))
             .count()
     }
+
+    ///
+    /// # classify
+    /// Counts the safe readings going up and the ones going down, readings of a
+    /// single level having no direction
+    ///
+    /// ## Returns
+    /// * `(usize, usize)` - Number of increasing and of decreasing safe readings
+    fn classify(&self) -> (usize, usize) {
+        self.readings
+            .iter()
+            .filter(|reading| reading.len() >= 2 && self.is_reading_safe(reading))
+            .fold((0, 0), |(increasing, decreasing), reading| {
+                if reading[1] > reading[0] {
+                    (increasing + 1, decreasing)
+                } else {
+                    (increasing, decreasing + 1)
+                }
+            })
+    }
+
+    /// Count safe readings with Problem Dampener, the readings being checked in parallel
+    fn count_safe_readings_with_dampener_parallel(&self) -> usize {
+        self.readings
+            .par_iter()
+            .filter(|reading| self.is_reading_safe_with_dampener(reading))
+            .count()
+    }
 }
 // Functions  =========================================================================== Functions
 
@@ -139,24 +185,36 @@ pub fn response_part_1() {
 
     let start = std::time::Instant::now();
 
-    let count = ReactorReport::from_str(INPUT)
-        .unwrap()
-        .count_safe_readings();
+    let report = ReactorReport::from_str(&INPUT).unwrap();
+    let count = report.count_safe_readings();
 
     let duration = start.elapsed();
 
+    let (increasing, decreasing) = report.classify();
+
     println!("Count: {}", count);
+    // Not in the `label: value` form, so that it isn't taken for an answer
+    println!("({increasing} increasing, {decreasing} decreasing)");
     println!("Duration: {duration:?}\n");
 }
 
-pub fn response_part_2() {
+///
+/// # response_part_2
+/// Counts the readings made safe by the Problem Dampener
+///
+/// ## Arguments
+/// * `parallel` - Whether to check the readings on every core
+pub fn response_part_2(parallel: bool) {
     println!("Day 02 - Part 2");
 
     let start = std::time::Instant::now();
 
-    let count = ReactorReport::from_str(INPUT)
-        .unwrap()
-        .count_safe_readings_with_dampener();
+    let report = ReactorReport::from_str(&INPUT).unwrap();
+    let count = if parallel {
+        report.count_safe_readings_with_dampener_parallel()
+    } else {
+        report.count_safe_readings_with_dampener()
+    };
 
     let duration = start.elapsed();
 
@@ -165,8 +223,10 @@ pub fn response_part_2() {
 }
 
 fn main() {
+    let parallel = !std::env::args().any(|arg| arg == SEQUENTIAL_FLAG);
+
     response_part_1();
-    response_part_2();
+    response_part_2(parallel);
 }
 
 // Tests ==================================================================================== Tests
@@ -189,13 +249,6 @@ mod tests {
         assert_eq!(data.readings, vec![vec![1, 2, 3], vec![4, 5, 6]]);
     }
 
-    #[test]
-    fn test_is_always_increasing() {
-        assert!(is_monotonic(&[1, 2, 3, 4])); // increasing
-        assert!(!is_monotonic(&[1, 3, 2, 4])); // not monotonic
-        assert!(is_monotonic(&[4, 3, 2, 1])); // decreasing - should be true!
-    }
-
     #[test]
     fn test_is_reading_safe() {
         let report = ReactorReport::from_str("1 2 3").unwrap();
@@ -262,4 +315,67 @@ mod tests {
 
         assert_eq!(data.count_safe_readings_with_dampener(), 3); // Adjusted based on dampener rules
     }
+
+    #[test]
+    fn test_is_reading_safe_with_k_removals() {
+        let report = ReactorReport::from_str(INPUT).unwrap();
+
+        // 9 twice in the way of 1, 2, 3
+        assert!(!report.is_reading_safe_with_k_removals(&[1, 9, 2, 9, 3], 1));
+        assert!(report.is_reading_safe_with_k_removals(&[1, 9, 2, 9, 3], 2));
+        assert!(report.is_reading_safe_with_k_removals(&[8, 6, 4, 4, 1], 1));
+        assert!(report.is_reading_safe_with_k_removals(&[1, 20], 1));
+
+        for reading in &report.readings {
+            assert_eq!(
+                report.is_reading_safe_with_k_removals(reading, 0),
+                report.is_reading_safe(reading),
+                "{reading:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_equal_adjacent_levels_are_unsafe() {
+        let report = ReactorReport::from_str(INPUT).unwrap();
+
+        // Rejected by the difference check already, monotonic as they otherwise are
+        assert!(!report.is_reading_safe(&[8, 6, 4, 4, 1]));
+        assert!(!report.is_reading_safe(&[1, 1, 2]));
+        assert!(!report.is_reading_safe(&[1, 1]));
+    }
+
+    #[test]
+    fn test_count_safe_readings_with_dampener_parallel() {
+        let data = ReactorReport::from_str(INPUT).unwrap();
+
+        assert_eq!(data.count_safe_readings_with_dampener_parallel(), 4);
+        assert_eq!(
+            data.count_safe_readings_with_dampener_parallel(),
+            data.count_safe_readings_with_dampener()
+        );
+    }
+
+    #[test]
+    fn test_first_violation() {
+        let report = ReactorReport::from_str(INPUT).unwrap();
+
+        assert_eq!(report.first_violation(&[1, 3, 2, 4]), Some(2)); // 2 goes down
+        assert_eq!(report.first_violation(&[1, 2, 7, 8, 9]), Some(2)); // 7 is too far
+        assert_eq!(report.first_violation(&[8, 6, 4, 4, 1]), Some(3)); // 4 again
+        assert_eq!(report.first_violation(&[1, 1, 2]), Some(1));
+        assert_eq!(report.first_violation(&[7, 6, 4, 2, 1]), None);
+        assert_eq!(report.first_violation(&[1]), None);
+    }
+
+    #[test]
+    fn test_classify() {
+        let data = ReactorReport::from_str(INPUT).unwrap();
+
+        // 7 6 4 2 1 goes down, 1 3 6 7 9 goes up
+        assert_eq!(data.classify(), (1, 1));
+
+        let data = ReactorReport::from_str("1 2 3\n9 8\n4 3 1\n5 9\n2 3\n7").unwrap();
+        assert_eq!(data.classify(), (2, 2));
+    }
 }
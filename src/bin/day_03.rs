@@ -6,13 +6,17 @@
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_03.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(3));
+// Every instruction, as alternatives so that a single pass finds them in order
+const INSTRUCTION_PATTERN: &str = r"(mul\((\d{1,3}),(\d{1,3})\))|(do\(\))|(don't\(\))";
+// Same, with multiply arguments of any width
+const WIDE_INSTRUCTION_PATTERN: &str = r"(mul\((\d+),(\d+)\))|(do\(\))|(don't\(\))";
 
 ///
 /// # `Instruction`
 /// Represents different types of instructions that can be found in the corrupted memory
 ///
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 enum Instruction {
     Multiply(usize, usize),
     Do,
@@ -21,11 +25,22 @@ enum Instruction {
 
 ///
 /// # `Program`
-/// Represents the parsed program with its sequence of instructions
+/// Represents the parsed program with its sequence of instructions, each with its byte
+/// offset in the corrupted memory
 ///
 #[derive(Debug)]
 struct Program {
-    instructions: Vec<Instruction>,
+    instructions: Vec<(usize, Instruction)>,
+}
+
+///
+/// # `ParseConfig`
+/// Options of `Program::parse_with`, the default being the puzzle's rules
+///
+#[derive(Debug, Clone, Copy, Default)]
+struct ParseConfig {
+    // Accept multiply arguments of any number of digits instead of 1 to 3
+    wide_arguments: bool,
 }
 
 impl FromStr for Program {
@@ -42,97 +57,142 @@ impl FromStr for Program {
     /// * `Result<Program, ()>` - The parsed program or an error
     ///
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut instructions = Vec::new();
-
-        // Compile regexes for different instructions
-        let mul_regex = regex::Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
-        let do_regex = regex::Regex::new(r"do\(\)").unwrap();
-        let dont_regex = regex::Regex::new(r"don't\(\)").unwrap();
-
-        // Track position to process instructions in order
-        let mut pos = 0;
-        while pos < s.len() {
-            if let Some(mat) = mul_regex.find_at(s, pos) {
-                if mat.start() == pos {
-                    let caps = mul_regex.captures(&s[pos..mat.end()]).unwrap();
-                    let a = caps[1].parse().unwrap();
-                    let b = caps[2].parse().unwrap();
-                    instructions.push(Instruction::Multiply(a, b));
-                    pos = mat.end();
-                    continue;
-                }
-            }
-            if let Some(mat) = do_regex.find_at(s, pos) {
-                if mat.start() == pos {
-                    instructions.push(Instruction::Do);
-                    pos = mat.end();
-                    continue;
-                }
-            }
-            if let Some(mat) = dont_regex.find_at(s, pos) {
-                if mat.start() == pos {
-                    instructions.push(Instruction::Dont);
-                    pos = mat.end();
-                    continue;
+        Program::parse_with(s, ParseConfig::default())
+    }
+}
+
+impl Program {
+    ///
+    /// # `parse_with`
+    /// Parses the input string to extract valid instructions, following the given options
+    ///
+    /// ## Arguments
+    /// * `s` - The input string containing corrupted memory
+    /// * `config` - The parsing options
+    ///
+    /// ## Returns
+    /// * `Result<Program, ()>` - The parsed program, or an error for an argument over `usize::MAX`
+    ///
+    fn parse_with(s: &str, config: ParseConfig) -> Result<Self, ()> {
+        let pattern = if config.wide_arguments {
+            WIDE_INSTRUCTION_PATTERN
+        } else {
+            INSTRUCTION_PATTERN
+        };
+        let instruction_regex = regex::Regex::new(pattern).unwrap();
+
+        let instructions = instruction_regex
+            .captures_iter(s)
+            .map(|caps| {
+                let offset = caps.get(0).unwrap().start();
+
+                if caps.get(1).is_some() {
+                    let a = caps[2].parse().map_err(|_| ())?;
+                    let b = caps[3].parse().map_err(|_| ())?;
+
+                    Ok((offset, Instruction::Multiply(a, b)))
+                } else if caps.get(4).is_some() {
+                    Ok((offset, Instruction::Do))
+                } else {
+                    Ok((offset, Instruction::Dont))
                 }
+            })
+            .collect::<Result<_, ()>>()?;
+
+        Ok(Program { instructions })
+    }
+
+    ///
+    /// # `sum_multiplications`
+    /// Sums the results of every multiplication. Products and sum are `u128` rather than
+    /// `usize`, so that wide arguments can't overflow
+    ///
+    /// ## Returns
+    /// * `u128` - The sum of all multiplications
+    ///
+    fn sum_multiplications(&self) -> u128 {
+        self.instructions
+            .iter()
+            .map(|(_, inst)| match inst {
+                Instruction::Multiply(a, b) => *a as u128 * *b as u128,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    ///
+    /// # `sum_multiplications_gated`
+    /// Sums the results of the multiplications enabled when they are reached, `don't()`
+    /// disabling the following ones until a `do()`. Computed in `u128`, like
+    /// `sum_multiplications`
+    ///
+    /// ## Returns
+    /// * `u128` - The sum of the enabled multiplications
+    ///
+    fn sum_multiplications_gated(&self) -> u128 {
+        self.enabled_multiplications()
+            .iter()
+            .map(|&(_, a, b)| a as u128 * b as u128)
+            .sum()
+    }
+
+    ///
+    /// # `enabled_multiplications`
+    /// Lists the multiplications enabled when they are reached, `don't()` disabling the
+    /// following ones until a `do()`
+    ///
+    /// ## Returns
+    /// * `Vec<(usize, usize, usize)>` - The byte offset and the arguments of each one
+    ///
+    fn enabled_multiplications(&self) -> Vec<(usize, usize, usize)> {
+        let mut enabled = true;
+        let mut multiplications = Vec::new();
+
+        for &(offset, ref inst) in &self.instructions {
+            match *inst {
+                Instruction::Multiply(a, b) if enabled => multiplications.push((offset, a, b)),
+                Instruction::Do => enabled = true,
+                Instruction::Dont => enabled = false,
+                Instruction::Multiply(..) => {}
             }
-            pos += 1;
         }
 
-        Ok(Program { instructions })
+        multiplications
     }
 }
 
 // Functions  =========================================================================== Functions
-pub fn response_part_1() {
+fn response_part_1(program: &Program) {
     println!("Day 03 - Part 1");
 
     let start = std::time::Instant::now();
 
-    let sum: usize = INPUT
-        .parse::<Program>()
-        .unwrap()
-        .instructions
-        .iter()
-        .filter_map(|inst| match inst {
-            Instruction::Multiply(a, b) => Some(a * b),
-            _ => None,
-        })
-        .sum();
+    let sum_all = program.sum_multiplications();
 
     let duration = start.elapsed();
 
-    println!("The sum of all multiplications is: {sum}");
+    println!("The sum of all multiplications is: {sum_all}");
     println!("Duration: {duration:?}\n");
 }
 
-pub fn response_part_2() {
+fn response_part_2(program: &Program) {
     println!("Day 03 - Part 2");
 
     let start = std::time::Instant::now();
 
-    let program = INPUT.parse::<Program>().unwrap();
-    let mut enabled = true;
-    let mut sum = 0;
-
-    for inst in program.instructions {
-        match inst {
-            Instruction::Multiply(a, b) if enabled => sum += a * b,
-            Instruction::Do => enabled = true,
-            Instruction::Dont => enabled = false,
-            _ => {}
-        }
-    }
+    let sum_enabled = program.sum_multiplications_gated();
 
     let duration = start.elapsed();
 
-    println!("The sum of all multiplications is: {sum}");
+    println!("The sum of all enabled multiplications is: {sum_enabled}");
     println!("Duration: {duration:?}");
 }
 
 fn main() {
-    response_part_1();
-    response_part_2();
+    let program = INPUT.parse::<Program>().unwrap();
+
+    response_part_1(&program);
+    response_part_2(&program);
 }
 
 // Tests ==================================================================================== Tests
@@ -145,7 +205,7 @@ mod tests {
         let input = "mul(2,3)";
         let program = input.parse::<Program>().unwrap();
         assert_eq!(program.instructions.len(), 1);
-        match &program.instructions[0] {
+        match &program.instructions[0].1 {
             Instruction::Multiply(a, b) => {
                 assert_eq!(*a, 2);
                 assert_eq!(*b, 3);
@@ -159,11 +219,11 @@ mod tests {
         let input = "do()don't()";
         let program = input.parse::<Program>().unwrap();
         assert_eq!(program.instructions.len(), 2);
-        match &program.instructions[0] {
+        match &program.instructions[0].1 {
             Instruction::Do => (),
             _ => panic!("Expected Do instruction"),
         }
-        match &program.instructions[1] {
+        match &program.instructions[1].1 {
             Instruction::Dont => (),
             _ => panic!("Expected Dont instruction"),
         }
@@ -192,7 +252,7 @@ mod tests {
         let sum: usize = program
             .instructions
             .iter()
-            .filter_map(|inst| match inst {
+            .filter_map(|(_, inst)| match inst {
                 Instruction::Multiply(a, b) => Some(a * b),
                 _ => None,
             })
@@ -208,7 +268,7 @@ mod tests {
         let mut enabled = true;
         let mut sum = 0;
 
-        for inst in program.instructions {
+        for (_, inst) in program.instructions {
             match inst {
                 Instruction::Multiply(a, b) if enabled => sum += a * b,
                 Instruction::Do => enabled = true,
@@ -219,4 +279,128 @@ mod tests {
 
         assert_eq!(sum, 6 + 9); // 4*5 is skipped due to don't()
     }
+
+    #[test]
+    fn test_solve() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        let program = input.parse::<Program>().unwrap();
+
+        assert_eq!(program.sum_multiplications(), 161);
+        assert_eq!(program.sum_multiplications_gated(), 48);
+    }
+
+    #[test]
+    fn test_instruction_order() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let program = input.parse::<Program>().unwrap();
+
+        // What the former position by position parser found
+        assert_eq!(
+            program.instructions,
+            vec![
+                (1, Instruction::Multiply(2, 4)),
+                (20, Instruction::Dont),
+                (28, Instruction::Multiply(5, 5)),
+                (48, Instruction::Multiply(11, 8)),
+                (59, Instruction::Do),
+                (64, Instruction::Multiply(8, 5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_large_input() {
+        let input = "mul(2,4)?don't()mul(1000,1)do()_mul(3,".repeat(100_000);
+
+        let start = std::time::Instant::now();
+        let program = input.parse::<Program>().unwrap();
+        let duration = start.elapsed();
+
+        assert_eq!(program.instructions.len(), 300_000);
+        assert!(duration.as_secs() < 5, "parsing took {duration:?}");
+    }
+
+    #[test]
+    fn test_sum_multiplications() {
+        let program = "mul(2,3)what()mul(4,5)mul(3,3)".parse::<Program>().unwrap();
+
+        assert_eq!(program.sum_multiplications(), 6 + 20 + 9);
+        assert_eq!(program.sum_multiplications_gated(), 6 + 20 + 9);
+    }
+
+    #[test]
+    fn test_sum_multiplications_gated() {
+        let program = "mul(2,3)don't()mul(4,5)do()mul(3,3)"
+            .parse::<Program>()
+            .unwrap();
+
+        assert_eq!(program.sum_multiplications_gated(), 6 + 9); // 4*5 is skipped due to don't()
+        assert_eq!(program.sum_multiplications(), 6 + 20 + 9);
+    }
+
+    #[test]
+    fn test_parse_wide_arguments() {
+        let input = "mul(1000,1000)mul(2,3)";
+
+        let program = input.parse::<Program>().unwrap();
+        assert_eq!(
+            program.instructions,
+            vec![(14, Instruction::Multiply(2, 3))]
+        );
+
+        let program = Program::parse_with(
+            input,
+            ParseConfig {
+                wide_arguments: true,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            program.instructions,
+            vec![
+                (0, Instruction::Multiply(1000, 1000)),
+                (14, Instruction::Multiply(2, 3))
+            ]
+        );
+        assert_eq!(program.sum_multiplications(), 1_000_006);
+
+        let too_wide = "mul(99999999999999999999,1)";
+        assert!(Program::parse_with(
+            too_wide,
+            ParseConfig {
+                wide_arguments: true
+            }
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_enabled_multiplications() {
+        let program = "mul(2,3)don't()mul(4,5)do()mul(3,3)"
+            .parse::<Program>()
+            .unwrap();
+
+        // mul(4,5) at offset 15 is disabled
+        assert_eq!(
+            program.enabled_multiplications(),
+            vec![(0, 2, 3), (27, 3, 3)]
+        );
+    }
+
+    #[test]
+    fn test_sum_wide_multiplications() {
+        let input = "mul(99999999999,99999999999)do()mul(4294967296,4294967296)";
+        let program = Program::parse_with(
+            input,
+            ParseConfig {
+                wide_arguments: true,
+            },
+        )
+        .unwrap();
+
+        let expected = 9_999_999_999_800_000_000_001 + (1_u128 << 64);
+        assert_eq!(program.sum_multiplications(), expected);
+        assert_eq!(program.sum_multiplications_gated(), expected);
+    }
 }
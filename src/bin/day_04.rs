@@ -24,7 +24,7 @@ use aoc_2024::Direction;
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_04.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(4));
 const TARGET: &str = "XMAS";
 
 // Structs ============================================================================== Structs
@@ -73,37 +73,34 @@ impl Grid {
         &self,
         row: usize,
         col: usize,
-        direction: Direction,
+        (row_delta, col_delta): (i32, i32),
         target: &str,
     ) -> bool {
         target.chars().enumerate().all(|(i, target_char)| {
-            let new_row = i32::try_from(row).unwrap()
-                + direction.row_delta() as i32 * i32::try_from(i).unwrap();
-            let new_col = i32::try_from(col).unwrap()
-                + direction.col_delta() as i32 * i32::try_from(i).unwrap();
+            let new_row = i32::try_from(row).unwrap() + row_delta * i32::try_from(i).unwrap();
+            let new_col = i32::try_from(col).unwrap() + col_delta * i32::try_from(i).unwrap();
 
             self.get_char(new_row, new_col)
                 .map_or(false, |c| c == target_char)
         })
     }
 
-    fn count_xmas_occurrences(&self) -> usize {
-        let directions = [
-            Direction::Up,
-            Direction::Down,
-            Direction::Left,
-            Direction::Right,
-            Direction::UpLeft,
-            Direction::UpRight,
-            Direction::DownLeft,
-            Direction::DownRight,
-        ];
-
+    ///
+    /// # `count_word_dirs`
+    /// Counts the occurrences of `word` in the grid, only reading along the given directions.
+    ///
+    /// ## Arguments
+    /// * `word` - The word to search for
+    /// * `dirs` - The `(row_delta, col_delta)` directions to read the word along
+    ///
+    /// ## Returns
+    /// * `usize` - The number of occurrences found
+    fn count_word_dirs(&self, word: &str, dirs: &[(i32, i32)]) -> usize {
         let mut count = 0;
         for row in 0..self.height {
             for col in 0..self.width {
-                for &direction in &directions {
-                    if self.check_word_at_position(row, col, direction, TARGET) {
+                for &delta in dirs {
+                    if self.check_word_at_position(row, col, delta, word) {
                         count += 1;
                     }
                 }
@@ -113,6 +110,13 @@ impl Grid {
         count
     }
 
+    fn count_xmas_occurrences(&self) -> usize {
+        let directions = Direction::all()
+            .map(|direction| (direction.row_delta() as i32, direction.col_delta() as i32));
+
+        self.count_word_dirs(TARGET, &directions)
+    }
+
     fn count_x_mas_patterns(&self) -> usize {
         let mut count = 0;
 
@@ -224,6 +228,15 @@ XMASAMXAMM";
         assert!(grid.count_xmas_occurrences() > 0);
     }
 
+    #[test]
+    fn test_count_word_dirs() {
+        let grid = create_test_grid();
+        let horizontal = grid.count_word_dirs(TARGET, &[(0, 1), (0, -1)]);
+
+        assert!(horizontal > 0);
+        assert!(horizontal < grid.count_xmas_occurrences());
+    }
+
     #[test]
     fn test_x_mas_patterns() {
         let input = "\
@@ -3,6 +3,7 @@
 /// Code for day 05 of Advent of Code 2024: Print Queue page ordering verification
 ///
 // Imports  ==============================================================================  Imports
+use aoc_2024::topo_sort;
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
@@ -90,26 +91,17 @@ impl PrintQueue {
             .collect()
     }
 
-    /// Orders a single update according to the rules
+    /// Orders a single update according to the rules (a topological sort of its pages).
+    /// Panics if the rules between the update's pages form a cycle.
     fn order_update(&self, update: &[i32]) -> Vec<i32> {
-        let mut ordered: Vec<i32> = update.to_vec();
-        let mut changed = true;
-
-        // Keep swapping adjacent elements until no more changes are needed
-        while changed {
-            changed = false;
-            for i in 0..ordered.len() - 1 {
-                for rule in &self.rules {
-                    // If we find two adjacent elements that violate a rule, swap them
-                    if ordered[i] == rule.after && ordered[i + 1] == rule.before {
-                        ordered.swap(i, i + 1);
-                        changed = true;
-                    }
-                }
-            }
-        }
+        let edges: Vec<(i32, i32)> = self
+            .rules
+            .iter()
+            .map(|rule| (rule.before, rule.after))
+            .collect();
 
-        ordered
+        topo_sort(update, &edges)
+            .unwrap_or_else(|cycle| panic!("Cyclic rules between pages {cycle:?}"))
     }
 
     /// Gets middle page numbers of reordered invalid updates
@@ -126,7 +118,7 @@ impl PrintQueue {
 }
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_05.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(5));
 
 // Functions  =========================================================================== Functions
 
@@ -212,4 +204,13 @@ mod tests {
 
         assert_eq!(ordered, vec![97, 75, 47, 61, 53]);
     }
+
+    #[test]
+    #[should_panic]
+    fn test_order_update_cycle() {
+        let input = "1|2\n2|1\n\n1,2";
+        let queue: PrintQueue = input.parse().unwrap();
+
+        queue.order_update(&[1, 2]);
+    }
 }
@@ -8,7 +8,7 @@ use indicatif::ProgressBar;
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_06.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(6));
 
 ///
 /// # `Guard`
@@ -33,6 +33,7 @@ impl Guard {
     /// ## Returns
     /// * `Guard` - A new Guard instance with initialized path history
     fn new(position: Point<i32>, direction: Direction) -> Self {
+        debug_assert!(direction.is_cardinal(), "the guard only faces cardinals");
         let path = vec![position];
 
         Guard {
@@ -43,34 +44,11 @@ impl Guard {
         }
     }
 
-    ///
-    /// # `direction_char`
-    /// Returns the character representation of the guard's current direction
-    ///
-    /// ## Returns
-    /// * `char` - The character representing the guard's direction
-    #[allow(dead_code)]
-    fn direction_char(&self) -> char {
-        match self.direction {
-            Direction::Up => '^',
-            Direction::Down => 'v',
-            Direction::Left => '<',
-            Direction::Right => '>',
-            _ => unreachable!(),
-        }
-    }
-
     ///
     /// # `turn_right`
     /// Rotates the guard 90 degrees clockwise
     fn turn_right(&mut self) {
-        self.direction = match self.direction {
-            Direction::Up => Direction::Right,
-            Direction::Right => Direction::Down,
-            Direction::Down => Direction::Left,
-            Direction::Left => Direction::Up,
-            _ => unreachable!(),
-        };
+        self.direction = self.direction.turn_right_90();
     }
 
     ///
@@ -97,20 +75,14 @@ impl Guard {
     /// ## Returns
     /// * `Point` - The position the guard would move to if they stepped forward
     fn get_next_position(&self) -> Point<i32> {
-        match self.direction {
-            Direction::Up => Point::new(self.position.x, self.position.y - 1),
-            Direction::Down => Point::new(self.position.x, self.position.y + 1),
-            Direction::Left => Point::new(self.position.x - 1, self.position.y),
-            Direction::Right => Point::new(self.position.x + 1, self.position.y),
-            _ => unreachable!(),
-        }
+        self.position + self.direction.unit_vector()
     }
 }
 
 ///
 /// # `Grid`
 /// Represents the game grid containing obstacles and a guard
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Grid {
     width: usize,
     height: usize,
@@ -177,7 +149,7 @@ impl Grid {
             for x in 0..self.width {
                 let p = Point::new(i32::try_from(x).unwrap(), i32::try_from(y).unwrap());
                 if p == self.guard.position {
-                    print!("{}", self.guard.direction_char());
+                    print!("{}", self.guard.direction);
                 } else if self.is_obstacle(p) {
                     print!("#");
                 } else {
@@ -307,13 +279,7 @@ impl Grid {
 
             // Check if hitting obstacle (including the new one)
             if temp_obstacles.contains(&next_pos) {
-                current_dir = match current_dir {
-                    Direction::Up => Direction::Right,
-                    Direction::Right => Direction::Down,
-                    Direction::Down => Direction::Left,
-                    Direction::Left => Direction::Up,
-                    _ => unreachable!(),
-                };
+                current_dir = current_dir.turn_right_90();
             } else {
                 current_pos = next_pos;
             }
@@ -354,13 +320,7 @@ impl Grid {
             }
 
             if self.is_obstacle(next_pos) {
-                current_dir = match current_dir {
-                    Direction::Up => Direction::Right,
-                    Direction::Right => Direction::Down,
-                    Direction::Down => Direction::Left,
-                    Direction::Left => Direction::Up,
-                    _ => unreachable!(),
-                };
+                current_dir = current_dir.turn_right_90();
             } else {
                 potential_positions.insert(next_pos);
                 current_pos = next_pos;
@@ -389,11 +349,10 @@ impl Grid {
 }
 
 // Functions  =========================================================================== Functions
-pub fn response_part_1() {
+fn response_part_1(mut grid: Grid) {
     println!("Day 06 - Part 1");
     let start = std::time::Instant::now();
 
-    let mut grid: Grid = INPUT.parse().unwrap();
     let visited = grid.simulate_guard_movement();
 
     let duration = start.elapsed();
@@ -402,11 +361,10 @@ pub fn response_part_1() {
     println!("Duration: {duration:?}\n");
 }
 
-pub fn response_part_2() {
+fn response_part_2(mut grid: Grid) {
     println!("Day 06 - Part 2");
     let start = std::time::Instant::now();
 
-    let mut grid: Grid = INPUT.parse().unwrap();
     let loop_positions = grid.count_possible_loop_positions();
 
     let duration = start.elapsed();
@@ -416,8 +374,11 @@ pub fn response_part_2() {
 }
 
 fn main() {
-    response_part_1();
-    response_part_2();
+    let grid: Grid = INPUT.parse().unwrap();
+
+    // Part 1 moves the guard, part 2 gets its own copy of the grid
+    response_part_1(grid.clone());
+    response_part_2(grid);
 }
 
 // Tests ==================================================================================== Tests
@@ -454,4 +415,13 @@ mod tests {
 
         assert_eq!(loop_positions, 6);
     }
+
+    #[test]
+    fn test_solve() {
+        let mut grid: Grid = TEST_INPUT.parse().unwrap();
+        let mut loop_grid = grid.clone();
+
+        assert_eq!(grid.simulate_guard_movement(), 41);
+        assert_eq!(loop_grid.count_possible_loop_positions(), 6);
+    }
 }
@@ -3,10 +3,11 @@
 /// Code for the day 07 of the Advent of Code challenge year 2024
 ///
 // Imports  ==============================================================================  Imports
+use aoc_2024::concat;
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_07.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(7));
 
 #[derive(Debug)]
 struct Equation {
@@ -70,10 +71,7 @@ impl Equation {
     ///
     /// * `i64` - Concatenated number
     fn concatenate(a: i64, b: i64) -> i64 {
-        let b_str = b.to_string();
-        let concatenated = format!("{}{}", a, b_str);
-
-        concatenated.parse().unwrap()
+        concat(a as u64, b as u64) as i64
     }
 
     ///
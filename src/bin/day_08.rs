@@ -8,7 +8,7 @@ use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_08.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(8));
 
 type MyPoint = Point<i32>;
 
@@ -135,7 +135,7 @@ impl AntennaMap {
     /// ## Returns
     /// * `bool` - True if the point is within the bounds
     fn is_within_bounds(&self, my_point: &MyPoint) -> bool {
-        my_point.x >= 0 && my_point.x < self.width && my_point.y >= 0 && my_point.y < self.height
+        my_point.in_bounds(self.width, self.height)
     }
 
     ///
@@ -172,8 +172,11 @@ impl AntennaMap {
 
                     // Find the GCD to get the smallest step size
                     let gcd = gcd(dx.abs(), dy.abs());
-                    let step_x = if gcd != 0 { dx / gcd } else { 0 };
-                    let step_y = if gcd != 0 { dy / gcd } else { 0 };
+                    let step = if gcd != 0 {
+                        MyPoint::new(dx / gcd, dy / gcd)
+                    } else {
+                        MyPoint::ORIGIN
+                    };
 
                     // Add points along the line in both directions
                     let mut current = MyPoint { x: a1.x, y: a1.y };
@@ -181,19 +184,14 @@ impl AntennaMap {
                     // Forward direction
                     while self.is_within_bounds(&current) {
                         antinodes.insert(current);
-                        current.x += step_x;
-                        current.y += step_y;
+                        current += step;
                     }
 
                     // Backward direction
-                    let mut current = MyPoint {
-                        x: a1.x - step_x,
-                        y: a1.y - step_y,
-                    };
+                    let mut current = MyPoint { x: a1.x, y: a1.y } - step;
                     while self.is_within_bounds(&current) {
                         antinodes.insert(current);
-                        current.x -= step_x;
-                        current.y -= step_y;
+                        current -= step;
                     }
                 }
             }
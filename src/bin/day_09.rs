@@ -9,7 +9,7 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_09.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(9));
 
 /// Represents a file on the virtual disk
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd)]
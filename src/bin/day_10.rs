@@ -6,7 +6,7 @@
 use std::{collections::HashSet, str::FromStr};
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_10.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(10));
 
 #[derive(Debug)]
 struct HeightMap {
@@ -55,36 +55,20 @@ impl HeightMap {
     }
 
     ///
-    /// # `calculate_trailhead_score`
-    /// Calculate the score of a trailhead.
-    ///
-    /// ## Arguments
-    /// * `start` - The starting position of the trailhead.
-    ///
-    /// ## Returns
-    /// * `usize` - The score of the trailhead.
-    fn calculate_trailhead_score(&self, start: (usize, usize)) -> usize {
-        let mut visited = HashSet::new();
-        let (_, reachable_nines) = self.traverse_paths(start, &mut visited, 0);
-
-        reachable_nines.len()
-    }
-
-    ///
-    /// # `calculate_trailhead_rating`
-    /// Calculate the rating of a trailhead.
+    /// # `trailhead_stats`
+    /// Calculate both the score and the rating of a trailhead from a single traversal.
     /// A trailhead's rating is the number of distinct hiking trails which begin at that trailhead.
     ///
     /// ## Arguments
     /// * `start` - The starting position of the trailhead.
     ///
     /// ## Returns
-    /// * `usize` - The rating of the trailhead.
-    fn calculate_trailhead_rating(&self, start: (usize, usize)) -> usize {
+    /// * `(usize, usize)` - The score and the rating of the trailhead.
+    fn trailhead_stats(&self, start: (usize, usize)) -> (usize, usize) {
         let mut visited = HashSet::new();
-        let (paths, _) = self.traverse_paths(start, &mut visited, 0);
+        let (paths, reachable_nines) = self.traverse_paths(start, &mut visited, 0);
 
-        paths
+        (reachable_nines.len(), paths)
     }
 
     ///
@@ -188,35 +172,55 @@ impl HeightMap {
         (total_paths, reachable_nines)
     }
 }
+
 // Functions  =========================================================================== Functions
-pub fn response_part_1() {
+///
+/// # `solve`
+/// Solve both parts, traversing each trailhead once.
+///
+/// ## Arguments
+/// * `height_map` - The parsed height map.
+///
+/// ## Returns
+/// * `(usize, usize)` - The total score and the total rating of all trailheads.
+fn solve(height_map: &HeightMap) -> (usize, usize) {
+    height_map
+        .find_trailheads()
+        .iter()
+        .map(|&pos| height_map.trailhead_stats(pos))
+        .fold((0, 0), |(total_score, total_rating), (score, rating)| {
+            (total_score + score, total_rating + rating)
+        })
+}
+
+///
+/// # `response_part_1`
+/// Traverse the trailheads, once for both parts.
+///
+/// ## Arguments
+/// * `height_map` - The parsed height map.
+///
+/// ## Returns
+/// * `usize` - The total rating, for part 2.
+fn response_part_1(height_map: &HeightMap) -> usize {
     println!("Day 10 - Part 1");
     let start = std::time::Instant::now();
 
-    let height_map = INPUT.parse::<HeightMap>().unwrap();
-    let trailheads = height_map.find_trailheads();
-    let total_score: usize = trailheads
-        .iter()
-        .map(|&pos| height_map.calculate_trailhead_score(pos))
-        .sum();
+    let (total_score, total_rating) = solve(height_map);
 
     let duration = start.elapsed();
 
     println!("Total score: {total_score}");
-    println!("Duration: {duration:?}");
+    println!("Duration: {duration:?}\n");
+
+    total_rating
 }
 
-pub fn response_part_2() {
+fn response_part_2(total_rating: usize) {
     println!("Day 10 - Part 2");
     let start = std::time::Instant::now();
 
-    let height_map = INPUT.parse::<HeightMap>().unwrap();
-    let trailheads = height_map.find_trailheads();
-    let total_rating: usize = trailheads
-        .iter()
-        .map(|&pos| height_map.calculate_trailhead_rating(pos))
-        .sum();
-
+    // Already computed by the traversal of part 1
     let duration = start.elapsed();
 
     println!("Total rating: {total_rating}");
@@ -224,8 +228,10 @@ pub fn response_part_2() {
 }
 
 fn main() {
-    response_part_1();
-    response_part_2();
+    let height_map = INPUT.parse::<HeightMap>().unwrap();
+
+    let total_rating = response_part_1(&height_map);
+    response_part_2(total_rating);
 }
 
 // Tests ==================================================================================== Tests
@@ -259,7 +265,7 @@ mod tests {
         let trailheads = height_map.find_trailheads();
         let total_score: usize = trailheads
             .iter()
-            .map(|&pos| height_map.calculate_trailhead_score(pos))
+            .map(|&pos| height_map.trailhead_stats(pos).0)
             .sum();
 
         assert_eq!(total_score, 36);
@@ -271,9 +277,16 @@ mod tests {
         let trailheads = height_map.find_trailheads();
         let total_rating: usize = trailheads
             .iter()
-            .map(|&pos| height_map.calculate_trailhead_rating(pos))
+            .map(|&pos| height_map.trailhead_stats(pos).1)
             .sum();
 
         assert_eq!(total_rating, 81);
     }
+
+    #[test]
+    fn test_solve() {
+        let height_map = EXAMPLE1.parse::<HeightMap>().unwrap();
+
+        assert_eq!(solve(&height_map), (36, 81));
+    }
 }
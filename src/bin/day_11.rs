@@ -3,13 +3,11 @@
 /// Code for the day 11 of the Advent of Code challenge year 2024
 ///
 // Imports  ==============================================================================  Imports
-use std::{
-    collections::{HashMap, VecDeque},
-    str::FromStr,
-};
+use aoc_2024::{num_digits, split_even, Memoizer};
+use std::{collections::VecDeque, str::FromStr};
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_11.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(11));
 
 #[derive(Debug)]
 struct Stones {
@@ -49,16 +47,7 @@ impl Stones {
         let mut current: Vec<(usize, u32)> = self
             .arrangement
             .iter()
-            .map(|&x| {
-                (
-                    x,
-                    if x == 0 {
-                        1
-                    } else {
-                        (x as f64).log10() as u32 + 1
-                    },
-                )
-            })
+            .map(|&x| (x, num_digits(x as u64)))
             .collect();
 
         for i in 0..n {
@@ -73,21 +62,13 @@ impl Stones {
                     let left = value / power;
                     let right = value % power;
                     // Calculate new digit counts directly
-                    let left_digits = if left == 0 {
-                        1
-                    } else {
-                        (left as f64).log10() as u32 + 1
-                    };
-                    let right_digits = if right == 0 {
-                        1
-                    } else {
-                        (right as f64).log10() as u32 + 1
-                    };
+                    let left_digits = num_digits(left as u64);
+                    let right_digits = num_digits(right as u64);
                     next.push((left, left_digits));
                     next.push((right, right_digits));
                 } else {
                     let new_value = value * MULTIPLIER;
-                    let new_digits = (new_value as f64).log10() as u32 + 1;
+                    let new_digits = num_digits(new_value as u64);
                     next.push((new_value, new_digits));
                 }
             }
@@ -110,7 +91,7 @@ impl Stones {
     /// ## Returns
     /// * `usize` - The number of stones after n iterations
     fn count_evolved_stones(&self, iterations: usize) -> usize {
-        let mut memo: HashMap<(usize, usize), usize> = HashMap::new(); // Cache for (stone, iteration) -> count
+        let mut memo = Memoizer::new(); // Cache for (stone, iteration) -> count
         let mut queue: VecDeque<_> = self
             .arrangement
             .iter()
@@ -141,43 +122,27 @@ impl Stones {
         &self,
         stone: usize,
         iterations: usize,
-        memo: &mut HashMap<(usize, usize), usize>,
+        memo: &mut Memoizer<(usize, usize), usize>,
     ) -> usize {
         // Base case: no more iterations
         if iterations == 0 {
             return 1;
         }
 
-        // Check if result is already cached
-        if let Some(&count) = memo.get(&(stone, iterations)) {
-            return count;
-        }
-
-        // Calculate result based on transformation rules
-        let result = if stone == 0 {
-            // Rule 1: 0 becomes 1
-            self.count_evolved_stones_recursive(1, iterations - 1, memo)
-        } else {
-            let digits = stone.to_string();
-            let digit_count = digits.len();
-
-            if digit_count % 2 == 0 {
+        // Calculate the result based on the transformation rules, or reuse the cached one
+        memo.get_or_compute((stone, iterations), |memo| {
+            if stone == 0 {
+                // Rule 1: 0 becomes 1
+                self.count_evolved_stones_recursive(1, iterations - 1, memo)
+            } else if let Some((left, right)) = split_even(stone as u64) {
                 // Rule 2: Split even-digit numbers
-                let mid = digit_count / 2;
-                let left = digits[..mid].parse::<usize>().unwrap();
-                let right = digits[mid..].parse::<usize>().unwrap();
-
-                self.count_evolved_stones_recursive(left, iterations - 1, memo)
-                    + self.count_evolved_stones_recursive(right, iterations - 1, memo)
+                self.count_evolved_stones_recursive(left as usize, iterations - 1, memo)
+                    + self.count_evolved_stones_recursive(right as usize, iterations - 1, memo)
             } else {
                 // Rule 3: Multiply by 2024
                 self.count_evolved_stones_recursive(stone * 2024, iterations - 1, memo)
             }
-        };
-
-        // Cache and return result
-        memo.insert((stone, iterations), result);
-        result
+        })
     }
 }
 
@@ -34,15 +34,15 @@
 /// - find_regions: Identifies all distinct plant regions
 /// - calculate_perimeter: Counts edges for part 1 pricing
 /// - calculate_sides: Counts distinct boundaries for part 2 pricing
-/// - flood_fill: Recursive algorithm for region detection
+/// - flood_fill: Stack-based algorithm for region detection
 ///
 // Imports  ==============================================================================  Imports
 use std::{collections::HashSet, ops::Add, str::FromStr};
 
-use aoc_2024::Direction;
+use aoc_2024::{parse_char_grid, Direction};
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_12.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(12));
 
 #[derive(Debug)]
 struct Garden {
@@ -55,13 +55,7 @@ impl FromStr for Garden {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let grid: Vec<Vec<char>> = s
-            .trim()
-            .lines()
-            .map(|line| line.chars().collect())
-            .collect();
-        let height = grid.len();
-        let width = if height > 0 { grid[0].len() } else { 0 };
+        let (grid, width, height) = parse_char_grid(s.trim());
 
         Ok(Garden {
             grid,
@@ -100,41 +94,46 @@ impl Garden {
 
     ///
     /// # `flood_fill`
-    /// Recursive function to fill a region of the garden with a plant type.
+    /// Fill a region of the garden with a plant type, with an explicit stack instead of
+    /// recursion so that a large region can't overflow the call stack.
     ///
     /// ## Arguments
     /// * `x` - The x coordinate of the cell
     /// * `y` - The y coordinate of the cell
     /// * `plant_type` - The type of plant to fill the region with
     /// * `visited` - A 2D vector of booleans to keep track of visited cells
-    /// * `region` - A vector of coordinates of the cells in the region
+    /// * `region` - A vector of (y, x) coordinates of the cells in the region
     fn flood_fill(
         &self,
         x: usize,
         y: usize,
         plant_type: char,
-        visited: &mut Vec<Vec<bool>>,
-        region: &mut Vec<(usize, usize)>, // Now stores (y, x)
+        visited: &mut [Vec<bool>],
+        region: &mut Vec<(usize, usize)>,
     ) {
-        if visited[y][x] || self.grid[y][x] != plant_type {
-            return;
-        }
+        let mut stack = vec![(x, y)];
 
-        visited[y][x] = true;
-        region.push((y, x)); // Changed from (x, y) to (y, x)
+        while let Some((x, y)) = stack.pop() {
+            if visited[y][x] || self.grid[y][x] != plant_type {
+                continue;
+            }
 
-        // Check all four adjacent cells
-        let neighbors = [
-            (x, y).add(aoc_2024::Direction::Left),
-            (x, y).add(aoc_2024::Direction::Right),
-            (x, y).add(aoc_2024::Direction::Down),
-            (x, y).add(aoc_2024::Direction::Up),
-        ];
+            visited[y][x] = true;
+            region.push((y, x));
 
-        for (nx, ny) in neighbors {
-            if nx < self.width && ny < self.height {
-                self.flood_fill(nx, ny, plant_type, visited, region);
-            }
+            // Reversed so that Left is popped first
+            let neighbors = [
+                (x, y).add(aoc_2024::Direction::Up),
+                (x, y).add(aoc_2024::Direction::Down),
+                (x, y).add(aoc_2024::Direction::Right),
+                (x, y).add(aoc_2024::Direction::Left),
+            ];
+
+            stack.extend(
+                neighbors
+                    .into_iter()
+                    .filter(|&(nx, ny)| nx < self.width && ny < self.height),
+            );
         }
     }
 
@@ -226,12 +225,7 @@ impl Garden {
         region: &[(usize, usize)],
         region_cells: &HashSet<(usize, usize)>,
     ) -> HashSet<((usize, usize), Direction)> {
-        let directions = [
-            Direction::Up,
-            Direction::Right,
-            Direction::Down,
-            Direction::Left,
-        ];
+        let directions = Direction::cardinals();
 
         region
             .iter()
@@ -266,12 +260,7 @@ impl Garden {
         &self,
         boundary_cells: HashSet<((usize, usize), Direction)>,
     ) -> Vec<HashSet<(usize, usize)>> {
-        let directions = [
-            Direction::Up,
-            Direction::Right,
-            Direction::Down,
-            Direction::Left,
-        ];
+        let directions = Direction::cardinals();
         let mut continuous_boundaries = Vec::new();
 
         for direction in directions {
@@ -310,12 +299,7 @@ impl Garden {
         let &start_cell = remaining_cells.iter().next()?;
         let mut continuous_boundary = HashSet::new();
         let mut cells_to_check = vec![start_cell];
-        let directions = [
-            Direction::Up,
-            Direction::Right,
-            Direction::Down,
-            Direction::Left,
-        ];
+        let directions = Direction::cardinals();
 
         while let Some(current_cell) = cells_to_check.pop() {
             if continuous_boundary.insert(current_cell) {
@@ -352,12 +336,10 @@ impl Garden {
 }
 
 // Functions  =========================================================================== Functions
-pub fn response_part_1() {
+fn response_part_1(garden: &Garden, regions: &[Vec<(usize, usize)>]) {
     println!("Day 12 - Part 1");
     let start = std::time::Instant::now();
 
-    let garden: Garden = INPUT.parse().unwrap();
-    let regions = garden.find_regions();
     let total_price: u64 = regions
         .iter()
         .map(|region| garden.calculate_region_price(region))
@@ -365,16 +347,14 @@ pub fn response_part_1() {
 
     let duration = start.elapsed();
 
-    println!("Total price: {}", total_price);
-    println!("Duration: {duration:?}");
+    println!("Total price: {total_price}");
+    println!("Duration: {duration:?}\n");
 }
 
-pub fn response_part_2() {
+fn response_part_2(garden: &Garden, regions: &[Vec<(usize, usize)>]) {
     println!("Day 12 - Part 2");
     let start = std::time::Instant::now();
 
-    let garden: Garden = INPUT.parse().unwrap();
-    let regions = garden.find_regions();
     let total_price: u64 = regions
         .iter()
         .map(|region| garden.calculate_region_price_part_2(region))
@@ -382,13 +362,17 @@ pub fn response_part_2() {
 
     let duration = start.elapsed();
 
-    println!("Total price: {}", total_price);
+    println!("Total price with sides: {total_price}");
     println!("Duration: {duration:?}");
 }
 
 fn main() {
-    response_part_1();
-    response_part_2();
+    // Both parts price the same regions, they are only found once
+    let garden: Garden = INPUT.parse().unwrap();
+    let regions = garden.find_regions();
+
+    response_part_1(&garden, &regions);
+    response_part_2(&garden, &regions);
 }
 
 // Tests ==================================================================================== Tests
@@ -571,4 +555,43 @@ AAAAAA";
 
         assert_eq!(total_price, 1206);
     }
+
+    #[test]
+    fn test_solve() {
+        let garden: Garden = LARGE_EXAMPLE.parse().unwrap();
+        let regions = garden.find_regions();
+
+        let (total_1, total_2) = regions.iter().fold((0, 0), |(total_1, total_2), region| {
+            (
+                total_1 + garden.calculate_region_price(region),
+                total_2 + garden.calculate_region_price_part_2(region),
+            )
+        });
+        assert_eq!((total_1, total_2), (1930, 1206));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let garden = Garden::from_str("AAB\nABB").unwrap();
+        let mut visited = vec![vec![false; garden.width]; garden.height];
+        let mut region = Vec::new();
+
+        garden.flood_fill(0, 0, 'A', &mut visited, &mut region);
+
+        // (y, x) cells, in the order they are reached
+        assert_eq!(region, vec![(0, 0), (1, 0), (0, 1)]);
+        assert!(visited[0][1] && !visited[1][1]);
+    }
+
+    #[test]
+    fn test_flood_fill_large_region() {
+        let (width, height) = (200, 200);
+        let input = vec!["A".repeat(width); height].join("\n");
+        let garden = Garden::from_str(&input).unwrap();
+
+        let regions = garden.find_regions();
+
+        assert_eq!(regions.len(), 1);
+        assert_eq!(regions[0].len(), width * height);
+    }
 }
@@ -23,7 +23,7 @@ use regex::Regex;
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_13.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(13));
 
 type MyPoint = Point<i64>;
 
@@ -131,6 +131,21 @@ impl ClawMachine {
             None
         }
     }
+
+    /// # `solution_detail`
+    /// Details the cheapest way to win the prize: how many times each button is pressed
+    /// and how many tokens it costs (3 tokens per A press, 1 token per B press).
+    ///
+    /// ## Arguments
+    /// * `offset` - Value added to prize coordinates, see `is_solvable`
+    ///
+    /// ## Returns
+    /// * `Some((a_presses, b_presses, tokens))` if the machine is solvable
+    /// * `None` otherwise
+    fn solution_detail(&self, offset: i64) -> Option<(i64, i64, i64)> {
+        self.is_solvable(offset)
+            .map(|(a_presses, b_presses)| (a_presses, b_presses, 3 * a_presses + b_presses))
+    }
 }
 
 // Functions  =========================================================================== Functions
@@ -143,8 +158,8 @@ pub fn response_part_1() {
     let mut total_tokens = 0;
 
     for machine in machines {
-        if let Some((a_presses, b_presses)) = machine.is_solvable(0) {
-            total_tokens += 3 * a_presses + b_presses;
+        if let Some((_, _, tokens)) = machine.solution_detail(0) {
+            total_tokens += tokens;
         }
     }
 
@@ -163,8 +178,8 @@ pub fn response_part_2() {
     let mut total_tokens = 0;
 
     for machine in machines {
-        if let Some((a_presses, b_presses)) = machine.is_solvable(10000000000000) {
-            total_tokens += 3 * a_presses + b_presses;
+        if let Some((_, _, tokens)) = machine.solution_detail(10000000000000) {
+            total_tokens += tokens;
         }
     }
 
@@ -218,4 +233,11 @@ Prize: X=18641, Y=10279";
 
         assert_eq!(total_tokens, 480);
     }
+
+    #[test]
+    fn test_solution_detail() {
+        let machine: ClawMachine = BUTTONS_1.split("\n\n").next().unwrap().parse().unwrap();
+
+        assert_eq!(machine.solution_detail(0), Some((80, 40, 280)));
+    }
 }
@@ -11,7 +11,7 @@
 ///
 /// ## Part 2
 /// Finds the optimal time to minimize the variance in the robots' positions. The variance is calculated separately for the x and y coordinates.
-/// The optimal time is determined using a precomputed inverse of the width modulo the height.
+/// The optimal time is determined using the Chinese Remainder Theorem on the best x and y offsets.
 ///
 /// ## Implementation Details
 /// - Uses regex for parsing robot data
@@ -25,17 +25,17 @@
 /// - Robot struct: Represents a robot with position and velocity
 /// - Robots struct: Manages a collection of robots and provides methods for movement and variance calculation
 /// - position_after: Computes the position of a robot after a given number of seconds
-/// - find_best_offset: Finds the best offset to minimize variance
+/// - find_best_offsets: Finds the x and y offsets minimizing variance
 /// - move_instances: Moves robots and returns their new positions
 ///
 // Imports  ==============================================================================  Imports
-use aoc_2024::calculate_variance;
+use aoc_2024::{crt, render, variance_xy, Point};
 
 use regex::Regex;
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_14.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(14));
 
 #[derive(Debug, Clone)]
 struct Robot {
@@ -96,32 +96,33 @@ impl Robots {
     }
 
     ///
-    /// # `find_best_offset`
-    /// Find the best offset to minimize the variance in the robots' positions.
-    /// The method iterates over possible offsets and calculates the variance for each offset.
+    /// # `find_best_offsets`
+    /// Find the offsets minimizing the variance of the robots' x and y positions.
+    /// x repeats every `width` seconds and y every `height` seconds, so both are searched
+    /// in a single pass over the offsets.
     ///
     /// ## Arguments
-    /// * `modulo` - The modulo value for the grid.
-    /// * `use_x` - A boolean flag indicating whether to use the x-coordinate for variance calculation.
+    /// * `width` - The width of the grid.
+    /// * `height` - The height of the grid.
     ///
     /// ## Returns
-    /// * `i32` - The best offset to minimize the variance.
-    fn find_best_offset(&self, modulo: i32, use_x: bool) -> i32 {
-        let mut best_variance = f64::MAX;
-        let mut best_offset = 0;
-
-        for offset in 0..modulo {
-            let positions: Vec<_> = self.move_instances(modulo, modulo, offset).collect();
-
-            let variance = if use_x {
-                calculate_variance(&positions)
-            } else {
-                calculate_variance(&positions.iter().map(|&(x, y)| (y, x)).collect::<Vec<_>>())
-            };
-
-            if variance < best_variance {
-                best_variance = variance;
-                best_offset = offset;
+    /// * `(i32, i32)` - The best offsets for the x and y coordinates.
+    fn find_best_offsets(&self, width: i32, height: i32) -> (i32, i32) {
+        let mut best_variance = (f64::MAX, f64::MAX);
+        let mut best_offset = (0, 0);
+
+        for offset in 0..width.max(height) {
+            let positions: Vec<_> = self.move_instances(width, height, offset).collect();
+            let (variance_x, variance_y) = variance_xy(&positions);
+
+            if offset < width && variance_x < best_variance.0 {
+                best_variance.0 = variance_x;
+                best_offset.0 = offset;
+            }
+
+            if offset < height && variance_y < best_variance.1 {
+                best_variance.1 = variance_y;
+                best_offset.1 = offset;
             }
         }
 
@@ -152,118 +153,151 @@ impl Robots {
             (new_x, new_y)
         })
     }
-}
 
-// Functions  =========================================================================== Functions
-pub fn response_part_1() {
-    println!("Day 14 - Part 1");
-    let start = std::time::Instant::now();
+    ///
+    /// # `safety_factor`
+    /// Compute the safety factor after a certain amount of seconds: the product of the
+    /// number of robots in each quadrant. Robots on the middle row or column are not counted.
+    ///
+    /// ## Arguments
+    /// * `seconds` - The amount of seconds to wait.
+    /// * `width` - The width of the grid.
+    /// * `height` - The height of the grid.
+    ///
+    /// ## Returns
+    /// * `i64` - The safety factor.
+    fn safety_factor(&self, seconds: i32, width: i32, height: i32) -> i64 {
+        let mut quadrant_counts = [0; 4];
 
-    let robots = INPUT
-        .trim()
-        .lines()
-        .map(|line| line.parse::<Robot>().unwrap())
-        .collect::<Vec<_>>();
-
-    let width = 101;
-    let height = 103;
-    let seconds = 100;
-
-    let mut quadrant_counts = [0; 4];
-
-    for robot in robots {
-        let (x, y) = robot.position_after(seconds, width, height);
-
-        // The center of the grid is not considered.
-        if x != width / 2 && y != height / 2 {
-            // Compute the quadrant of the robot.
-            let quadrant = if x < width / 2 {
-                // x < width / 2 corresponds to the left side of the grid.
-                if y < height / 2 {
-                    // y < height / 2 corresponds to the top side of the grid.
-                    0
-                } else {
-                    // y >= height / 2 corresponds to the bottom side of the grid.
-                    2
-                }
-            } else {
-                // x >= width / 2 corresponds to the right side of the grid.
-                if y < height / 2 {
-                    // y < height / 2 corresponds to the top side of the grid.
-                    1
+        for robot in &self.instances {
+            let (x, y) = robot.position_after(seconds, width, height);
+
+            // The center of the grid is not considered.
+            if x != width / 2 && y != height / 2 {
+                // Compute the quadrant of the robot.
+                let quadrant = if x < width / 2 {
+                    // x < width / 2 corresponds to the left side of the grid.
+                    if y < height / 2 {
+                        // y < height / 2 corresponds to the top side of the grid.
+                        0
+                    } else {
+                        // y >= height / 2 corresponds to the bottom side of the grid.
+                        2
+                    }
                 } else {
-                    // y >= height / 2 corresponds to the bottom side of the grid.
-                    3
-                }
-            };
+                    // x >= width / 2 corresponds to the right side of the grid.
+                    if y < height / 2 {
+                        // y < height / 2 corresponds to the top side of the grid.
+                        1
+                    } else {
+                        // y >= height / 2 corresponds to the bottom side of the grid.
+                        3
+                    }
+                };
 
-            quadrant_counts[quadrant] += 1;
+                quadrant_counts[quadrant] += 1;
+            }
         }
+
+        quadrant_counts.iter().product::<i64>()
+    }
+
+    ///
+    /// # `tree_time`
+    /// Find the first time at which the robots are the most grouped together on both axes,
+    /// which is when they draw the Christmas tree.
+    ///
+    /// ## Arguments
+    /// * `width` - The width of the grid.
+    /// * `height` - The height of the grid.
+    ///
+    /// ## Returns
+    /// * `i32` - The time at which the tree appears.
+    fn tree_time(&self, width: i32, height: i32) -> i32 {
+        let (best_offset_x, best_offset_y) = self.find_best_offsets(width, height);
+        let (best_offset_x, best_offset_y) = (i64::from(best_offset_x), i64::from(best_offset_y));
+
+        // The tree appears when x is at its best offset modulo the width and y at its best
+        // offset modulo the height. Width and height are coprime, so the Chinese Remainder
+        // Theorem gives a single time in 0..width * height.
+        crt(&[
+            (best_offset_x, i64::from(width)),
+            (best_offset_y, i64::from(height)),
+        ])
+        .unwrap() as i32
     }
 
-    let safety_factor = quadrant_counts.iter().product::<i32>();
+    ///
+    /// # `display`
+    /// Render the robots' positions after a certain amount of seconds, '@' marking a robot.
+    ///
+    /// ## Arguments
+    /// * `seconds` - The amount of seconds to wait.
+    /// * `width` - The width of the grid.
+    /// * `height` - The height of the grid.
+    ///
+    /// ## Returns
+    /// * `String` - The rendered grid.
+    #[allow(dead_code)]
+    fn display(&self, seconds: i32, width: i32, height: i32) -> String {
+        let positions = self
+            .move_instances(width, height, seconds)
+            .map(|(x, y)| Point::new(x as usize, y as usize))
+            .collect::<Vec<_>>();
 
-    let duration = start.elapsed();
+        render(&positions, width as usize, height as usize, '@')
+    }
+}
 
-    println!("Safety factor: {safety_factor}");
-    println!("Duration: {duration:?}");
+// Functions  =========================================================================== Functions
+///
+/// # `parse_robots`
+/// Parse one robot per line of the puzzle input.
+///
+/// ## Arguments
+/// * `input` - The puzzle input.
+///
+/// ## Returns
+/// * `Robots` - The robots.
+fn parse_robots(input: &str) -> Robots {
+    Robots::new(
+        input
+            .trim()
+            .lines()
+            .map(|line| line.parse::<Robot>().unwrap())
+            .collect(),
+    )
 }
 
-pub fn response_part_2() {
-    println!("Day 14 - Part 2");
+fn response_part_1(robots: &Robots) {
+    println!("Day 14 - Part 1");
     let start = std::time::Instant::now();
 
-    let robots = INPUT
-        .trim()
-        .lines()
-        .map(|line| line.parse::<Robot>().unwrap())
-        .collect::<Vec<_>>();
-
-    let width: i32 = 101;
-    let height: i32 = 103;
-
-    let robots = Robots::new(robots);
-    let best_offset_x = robots.find_best_offset(width, true) as i64;
-    let best_offset_y = robots.find_best_offset(height, false) as i64;
-
-    // The inverse of the width modulo the height is calculated to optimize the time calculation.
-    // The inverse is used to align the best offsets for the x and y coordinates.
-    // It's calculated by finding the value of `i` such that `(i * width) % height == 1`.
-    let inv_w = i64::from((0..height).find(|&i| (i * width) % height == 1).unwrap());
-
-    // The optimal time is calculated using a formula that combines the best offsets for the x and y coordinates.
-    //
-    //  The formula `(best_offset_x + INV_W * (best_offset_y - best_offset_x) * i64::from(width))` computes the optimal time in a way that
-    // aligns the best offsets for both coordinates.
-    //
-    //  `rem_euclid` is used to ensure the result is within the valid range of time (0 to `width * height - 1`).
-    let optimal_time = best_offset_x + inv_w * (best_offset_y - best_offset_x) * i64::from(width);
-    let optimal_time_within_bounds = optimal_time.rem_euclid(i64::from(width * height));
+    let safety_factor = robots.safety_factor(100, 101, 103);
 
     let duration = start.elapsed();
 
-    // print the robots' positions after `optimal_time_within_bounds` time.
-    let positions = robots
-        .move_instances(width, height, optimal_time_within_bounds as i32)
-        .collect::<Vec<_>>();
+    println!("Safety factor: {safety_factor}");
+    println!("Duration: {duration:?}\n");
+}
 
-    let mut grid = vec![vec!['.'; width as usize]; height as usize];
+fn response_part_2(robots: &Robots) {
+    println!("Day 14 - Part 2");
+    let start = std::time::Instant::now();
 
-    for (x, y) in positions {
-        grid[y as usize][x as usize] = '@';
-    }
+    let optimal_time = robots.tree_time(101, 103);
 
-    for row in grid {
-        println!("{}", row.iter().collect::<String>());
-    }
+    let duration = start.elapsed();
 
-    println!("Optimal time: {optimal_time_within_bounds}");
+    println!("Optimal time: {optimal_time}");
     println!("Duration: {duration:?}");
 }
 
 fn main() {
-    response_part_1();
-    response_part_2();
+    let robots = parse_robots(&INPUT);
+
+    response_part_1(&robots);
+    response_part_2(&robots);
 }
 
 // Tests ==================================================================================== Tests
@@ -323,4 +357,11 @@ p=9,5 v=-3,-3";
 
         assert_eq!(safety_factor, 12);
     }
+
+    #[test]
+    fn test_solve() {
+        let robots = parse_robots(TEST_INPUT);
+
+        assert_eq!(robots.safety_factor(100, 11, 7), 12);
+    }
 }
@@ -3,13 +3,13 @@
 /// Code for the day 15 of the Advent of Code challenge year 2024
 ///
 // Imports  ==============================================================================  Imports
-use aoc_2024::Direction;
+use aoc_2024::{parse_moves, Direction, Grid, Point};
+use std::fmt;
 use std::str::FromStr;
 use std::time::Instant;
-use std::{fmt, mem};
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_15.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(15));
 
 #[derive(PartialEq, Clone, Copy, Debug)]
 enum Tile {
@@ -35,13 +35,15 @@ impl From<char> for Tile {
 }
 
 impl Tile {
-    fn double(self) -> impl Iterator<Item = Self> {
-        match self {
-            Tile::Empty => [Tile::Empty, Tile::Empty].into_iter(),
-            Tile::Wall => [Tile::Wall, Tile::Wall].into_iter(),
-            Tile::Object => [Tile::BoxLeft, Tile::BoxRight].into_iter(),
-            Tile::Robot => [Tile::Robot, Tile::Empty].into_iter(),
-            Tile::BoxLeft | Tile::BoxRight => panic!(),
+    // The tile at `sub_col` (0 or 1) once this tile is doubled in width
+    fn double(self, sub_col: usize) -> Self {
+        match (self, sub_col) {
+            (Tile::Object, 0) => Tile::BoxLeft,
+            (Tile::Object, _) => Tile::BoxRight,
+            (Tile::Robot, 0) => Tile::Robot,
+            (Tile::Robot, _) => Tile::Empty,
+            (Tile::BoxLeft | Tile::BoxRight, _) => panic!(),
+            (tile, _) => tile,
         }
     }
 }
@@ -59,12 +61,10 @@ impl From<Tile> for char {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Warehouse {
-    grid: Vec<Vec<Tile>>,
+    grid: Grid<Tile>,
     robot: (usize, usize),
-    width: usize,
-    height: usize,
 }
 
 impl FromStr for Warehouse {
@@ -86,21 +86,15 @@ impl FromStr for Warehouse {
             grid.push(row);
         }
 
-        let width = grid[0].len();
-        let height = grid.len();
+        let grid = Grid::from_rows(grid).map_err(|_| ())?;
 
-        Ok(Warehouse {
-            grid,
-            robot,
-            width,
-            height,
-        })
+        Ok(Warehouse { grid, robot })
     }
 }
 
 impl fmt::Display for Warehouse {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for row in &self.grid {
+        for row in self.grid.rows() {
             for tile in row {
                 write!(f, "{}", char::from(*tile))?;
             }
@@ -113,15 +107,9 @@ impl fmt::Display for Warehouse {
 
 impl Warehouse {
     fn scale_width(&mut self) {
-        let mut temp: Vec<Vec<Tile>> = Vec::new();
-        mem::swap(&mut self.grid, &mut temp);
-
-        self.grid = temp
-            .into_iter()
-            .map(|row| row.into_iter().flat_map(Tile::double).collect())
-            .collect();
-
-        self.width *= 2;
+        self.grid = self
+            .grid
+            .scale(2, 1, |&tile, sub_col, _| tile.double(sub_col));
         self.robot.1 *= 2;
     }
 
@@ -130,35 +118,35 @@ impl Warehouse {
 
         if self.can_move_tile(row, col, direction) {
             self.move_tile(row, col, direction);
-            self.robot = self.robot + direction;
+            self.robot += direction;
         }
     }
 
     fn move_tile(&mut self, row: usize, col: usize, direction: Direction) {
         let (next_row, next_col) = (row, col) + direction;
-        let next_tile = self.grid[next_row][next_col];
+        let next_tile = self.grid[Point::new(next_col, next_row)];
 
         match next_tile {
             Tile::Empty => {
-                self.grid[next_row][next_col] = self.grid[row][col];
-                self.grid[row][col] = Tile::Empty;
+                self.grid[Point::new(next_col, next_row)] = self.grid[Point::new(col, row)];
+                self.grid[Point::new(col, row)] = Tile::Empty;
             }
             Tile::Object => {
                 self.move_tile(next_row, next_col, direction);
-                self.grid[next_row][next_col] = self.grid[row][col];
-                self.grid[row][col] = Tile::Empty;
+                self.grid[Point::new(next_col, next_row)] = self.grid[Point::new(col, row)];
+                self.grid[Point::new(col, row)] = Tile::Empty;
             }
             Tile::BoxRight => {
                 self.move_tile(next_row, next_col - 1, direction);
                 self.move_tile(next_row, next_col, direction);
-                self.grid[next_row][next_col] = self.grid[row][col];
-                self.grid[row][col] = Tile::Empty;
+                self.grid[Point::new(next_col, next_row)] = self.grid[Point::new(col, row)];
+                self.grid[Point::new(col, row)] = Tile::Empty;
             }
             Tile::BoxLeft => {
                 self.move_tile(next_row, next_col + 1, direction);
                 self.move_tile(next_row, next_col, direction);
-                self.grid[next_row][next_col] = self.grid[row][col];
-                self.grid[row][col] = Tile::Empty;
+                self.grid[Point::new(next_col, next_row)] = self.grid[Point::new(col, row)];
+                self.grid[Point::new(col, row)] = Tile::Empty;
             }
             Tile::Wall => panic!(),
             Tile::Robot => panic!(),
@@ -167,7 +155,7 @@ impl Warehouse {
 
     fn can_move_tile(&self, row: usize, col: usize, direction: Direction) -> bool {
         let (next_row, next_col) = (row, col) + direction;
-        let next_tile = self.grid[next_row][next_col];
+        let next_tile = self.grid[Point::new(next_col, next_row)];
 
         match next_tile {
             Tile::Empty => true,
@@ -203,9 +191,10 @@ impl Warehouse {
 
     fn sum_gps_coordinates(&self) -> usize {
         let mut sum = 0;
-        for row in 0..self.height {
-            for col in 0..self.width {
-                if self.grid[row][col] == Tile::Object || self.grid[row][col] == Tile::BoxLeft {
+        for row in 0..self.grid.height() {
+            for col in 0..self.grid.width() {
+                let tile = self.grid[Point::new(col, row)];
+                if tile == Tile::Object || tile == Tile::BoxLeft {
                     sum += Self::gps_coordinate(row, col);
                 }
             }
@@ -216,66 +205,61 @@ impl Warehouse {
 }
 
 // Functions  =========================================================================== Functions
-pub fn response_part_1() {
-    println!("Day 15 - Part 1");
-    let start = Instant::now();
+///
+/// # `parse_input`
+/// Parse the warehouse and the moves, separated by an empty line.
+///
+/// ## Arguments
+/// * `input` - The puzzle input
+///
+/// ## Returns
+/// * `(Warehouse, Vec<Direction>)` - The warehouse and the robot's moves
+fn parse_input(input: &str) -> (Warehouse, Vec<Direction>) {
+    let mut parts = input.split("\n\n");
 
-    let mut parts = INPUT.split("\n\n");
-    let warehouse_str = parts.next().unwrap();
-    let moves: Vec<Direction> = parts
-        .next()
-        .unwrap()
-        .replace("\n", "")
-        .chars()
-        .map(Direction::from)
-        .collect();
+    let warehouse: Warehouse = parts.next().unwrap().parse().unwrap();
+    let moves = parse_moves(parts.next().unwrap()).unwrap();
 
-    let mut warehouse: Warehouse = warehouse_str.parse().unwrap();
+    (warehouse, moves)
+}
+
+fn response_part_1(mut warehouse: Warehouse, moves: &[Direction]) {
+    println!("Day 15 - Part 1");
+    let start = Instant::now();
 
-    for direction in moves {
+    for &direction in moves {
         warehouse.move_robot(direction);
     }
-
     let sum = warehouse.sum_gps_coordinates();
 
     let duration = start.elapsed();
 
-    println!("Sum of GPS coordinates: {}", sum);
-    println!("Duration: {duration:?}");
+    println!("Sum of GPS coordinates: {sum}");
+    println!("Duration: {duration:?}\n");
 }
 
-pub fn response_part_2() {
+fn response_part_2(mut warehouse: Warehouse, moves: &[Direction]) {
     println!("Day 15 - Part 2");
-    let start = std::time::Instant::now();
-
-    let mut parts = INPUT.split("\n\n");
-    let warehouse = parts.next().unwrap();
-    let moves = parts.next().unwrap();
-
-    let mut warehouse = warehouse.parse::<Warehouse>().unwrap();
-    let moves: Vec<Direction> = moves
-        .lines()
-        .flat_map(|line| line.chars())
-        .map(Direction::from)
-        .collect();
+    let start = Instant::now();
 
     warehouse.scale_width();
-
-    for direction in moves {
+    for &direction in moves {
         warehouse.move_robot(direction);
     }
-
-    let sum = warehouse.sum_gps_coordinates();
+    let scaled_sum = warehouse.sum_gps_coordinates();
 
     let duration = start.elapsed();
 
-    println!("Sum of GPS coordinates: {}", sum);
+    println!("Sum of GPS coordinates in the scaled warehouse: {scaled_sum}");
     println!("Duration: {duration:?}");
 }
 
 fn main() {
-    response_part_1();
-    response_part_2();
+    let (warehouse, moves) = parse_input(&INPUT);
+
+    // Each part moves the robot in its own copy of the warehouse
+    response_part_1(warehouse.clone(), &moves);
+    response_part_2(warehouse, &moves);
 }
 
 // Tests ==================================================================================== Tests
@@ -311,13 +295,7 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
         let mut parts = TEST_INPUT.split("\n\n");
 
         let mut warehouse: Warehouse = parts.next().unwrap().parse().unwrap();
-        let moves: Vec<Direction> = parts
-            .next()
-            .unwrap()
-            .replace("\n", "")
-            .chars()
-            .map(Direction::from)
-            .collect();
+        let moves = parse_moves(parts.next().unwrap()).unwrap();
 
         for direction in moves {
             warehouse.move_robot(direction);
@@ -331,13 +309,7 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
         let mut parts = TEST_INPUT.split("\n\n");
 
         let mut warehouse: Warehouse = parts.next().unwrap().parse().unwrap();
-        let moves: Vec<Direction> = parts
-            .next()
-            .unwrap()
-            .replace("\n", "")
-            .chars()
-            .map(Direction::from)
-            .collect();
+        let moves = parse_moves(parts.next().unwrap()).unwrap();
 
         warehouse.scale_width();
 
@@ -351,4 +323,21 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
 
         assert_eq!(warehouse.sum_gps_coordinates(), 9021);
     }
+
+    #[test]
+    fn test_solve() {
+        let (warehouse, moves) = parse_input(TEST_INPUT);
+
+        let mut normal = warehouse.clone();
+        let mut scaled = warehouse;
+        scaled.scale_width();
+
+        for &direction in &moves {
+            normal.move_robot(direction);
+            scaled.move_robot(direction);
+        }
+
+        assert_eq!(normal.sum_gps_coordinates(), 10092);
+        assert_eq!(scaled.sum_gps_coordinates(), 9021);
+    }
 }
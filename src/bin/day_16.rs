@@ -26,7 +26,7 @@ use fnv::FnvHashSet;
 use std::{cmp::Ordering, collections::BinaryHeap, str::FromStr};
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_16.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(16));
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 enum Tile {
@@ -186,11 +186,11 @@ impl Maze {
             // Update visited state
             visited[row][col][direction as usize] = Some(cost);
 
-            let (dy, dx) = direction.into();
+            let (dy, dx): (i32, i32) = direction.into();
 
             // Try moving forward
-            let new_row = (row as i32 + dy as i32) as usize;
-            let new_col = (col as i32 + dx as i32) as usize;
+            let new_row = (row as i32 + dy) as usize;
+            let new_col = (col as i32 + dx) as usize;
 
             if new_row < rows && new_col < cols && self.grid[new_row][new_col] != Tile::Wall {
                 let mut new_path = path.clone();
@@ -205,10 +205,7 @@ impl Maze {
             }
 
             // Try turning
-            for new_direction in [
-                direction.turn_clockwise(),
-                direction.turn_counterclockwise(),
-            ] {
+            for new_direction in [direction.turn_right_90(), direction.turn_left_90()] {
                 let new_path = path.clone();
                 queue.push(State {
                     cost: cost + 1000,
@@ -222,36 +219,47 @@ impl Maze {
         final_cost.map(|cost| (cost, path_tiles.len()))
     }
 }
+
 // Functions  =========================================================================== Functions
-pub fn response_part_1() {
+///
+/// # `response_part_1`
+/// Run the best paths search, once for both parts.
+///
+/// ## Arguments
+/// * `maze` - The parsed maze
+///
+/// ## Returns
+/// * `usize` - The number of tiles on the best paths, for part 2
+fn response_part_1(maze: &Maze) -> usize {
     println!("Day 16 - Part 1");
     let start = std::time::Instant::now();
 
-    let maze = Maze::from_str(INPUT).unwrap();
-    let result = maze.find_all_best_paths().unwrap();
+    let (min_cost, optimal_tile_count) = maze.find_all_best_paths().unwrap();
 
     let duration = start.elapsed();
 
-    println!("Lowest possible score: {}", result.0);
-    println!("Duration: {duration:?}");
+    println!("Lowest possible score: {min_cost}");
+    println!("Duration: {duration:?}\n");
+
+    optimal_tile_count
 }
 
-pub fn response_part_2() {
+fn response_part_2(optimal_tile_count: usize) {
     println!("Day 16 - Part 2");
     let start = std::time::Instant::now();
 
-    let maze = Maze::from_str(INPUT).unwrap();
-    let result = maze.find_all_best_paths().unwrap();
-
+    // Already counted by the search of part 1
     let duration = start.elapsed();
 
-    println!("Lowest possible score: {}", result.1);
+    println!("Tiles on the best paths: {optimal_tile_count}");
     println!("Duration: {duration:?}");
 }
 
 fn main() {
-    response_part_1();
-    response_part_2();
+    let maze = Maze::from_str(&INPUT).unwrap();
+
+    let optimal_tile_count = response_part_1(&maze);
+    response_part_2(optimal_tile_count);
 }
 
 // Tests ==================================================================================== Tests
@@ -311,4 +319,13 @@ mod tests {
 
         assert_eq!(result.0, 11048);
     }
+
+    #[test]
+    fn test_solve() {
+        let maze = Maze::from_str(EXAMPLE_1).unwrap();
+        assert_eq!(maze.find_all_best_paths(), Some((7036, 45)));
+
+        let maze = Maze::from_str(EXAMPLE_2).unwrap();
+        assert_eq!(maze.find_all_best_paths(), Some((11048, 64)));
+    }
 }
@@ -24,7 +24,7 @@ use regex::Regex;
 use std::str::FromStr;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_17.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(17));
 
 /// Represents the 3-bit computer with registers and program execution state
 #[derive(Debug)]
@@ -204,39 +204,49 @@ impl FromStr for ProgramInput {
 }
 
 // Functions  =========================================================================== Functions
-pub fn response_part_1() {
-    println!("Day 17 - Part 1");
-    let start = std::time::Instant::now();
-
-    let input: ProgramInput = INPUT.parse().unwrap();
+///
+/// # `run_program`
+/// Runs the program with its initial registers
+///
+/// ## Arguments
+/// * `input` - The parsed program input
+///
+/// ## Returns
+/// * `String` - The comma-separated output of the program
+fn run_program(input: &ProgramInput) -> String {
     let mut computer = Computer::new(
         input.register_a,
         input.register_b,
         input.register_c,
-        input.program,
+        input.program.clone(),
     );
 
     computer.run();
 
-    let output = computer
+    computer
         .output
         .iter()
         .map(|&n| n.to_string())
         .collect::<Vec<String>>()
-        .join(",");
-
-    let duration = start.elapsed();
-
-    println!("Output: {output}");
-    println!("Duration: {duration:?}");
+        .join(",")
 }
 
-pub fn response_part_2() {
-    println!("Day 17 - Part 2");
-    let start = std::time::Instant::now();
-
-    let input: ProgramInput = INPUT.parse().unwrap();
-    let program = input.program;
+///
+/// # `find_self_replicating_a`
+/// Finds the lowest value of register A for which the program outputs itself
+///
+/// ## Algorithm
+/// The program consumes register A 3 bits at a time, so the output is built in reverse:
+/// for each position from the end of the program, A is shifted left by 3 bits and
+/// incremented until the output matches the end of the program from that position.
+///
+/// ## Arguments
+/// * `input` - The parsed program input
+///
+/// ## Returns
+/// * `i64` - The lowest value of register A
+fn find_self_replicating_a(input: &ProgramInput) -> i64 {
+    let program = &input.program;
 
     let mut a = 0;
     // Iterate through positions from end to start
@@ -251,22 +261,45 @@ pub fn response_part_2() {
             computer.run();
 
             // Check if the output matches the expected sequence
-            let expected: Vec<i64> = program[pos..].to_vec();
-            if computer.output == expected {
+            if computer.output == program[pos..] {
                 break;
             }
             a += 1;
         }
     }
 
+    a
+}
+
+fn response_part_1(input: &ProgramInput) {
+    println!("Day 17 - Part 1");
+    let start = std::time::Instant::now();
+
+    let output = run_program(input);
+
     let duration = start.elapsed();
+
+    println!("Output: {output}");
+    println!("Duration: {duration:?}\n");
+}
+
+fn response_part_2(input: &ProgramInput) {
+    println!("Day 17 - Part 2");
+    let start = std::time::Instant::now();
+
+    let a = find_self_replicating_a(input);
+
+    let duration = start.elapsed();
+
     println!("Result: {a}");
     println!("Duration: {duration:?}");
 }
 
 fn main() {
-    response_part_1();
-    response_part_2();
+    let input: ProgramInput = INPUT.parse().unwrap();
+
+    response_part_1(&input);
+    response_part_2(&input);
 }
 
 // Tests ==================================================================================== Tests
@@ -312,4 +345,33 @@ Program: 0,1,5,4,3,0";
         computer.run();
         assert_eq!(computer.output, vec![0, 1, 2]);
     }
+
+    #[test]
+    fn test_run_program() {
+        let input = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+        let program_input: ProgramInput = input.parse().unwrap();
+
+        assert_eq!(run_program(&program_input), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+        let input: ProgramInput = input.parse().unwrap();
+
+        assert_eq!(run_program(&input), "5,7,3,0");
+        assert_eq!(find_self_replicating_a(&input), 117440);
+    }
 }
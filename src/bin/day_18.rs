@@ -3,12 +3,12 @@
 /// Code for the day 18 of the Advent of Code challenge year 2024
 ///
 // Imports  ==============================================================================  Imports
-use aoc_2024::{Direction, Point};
+use aoc_2024::{bfs, Direction, Point};
 use regex::Regex;
-use std::{collections::VecDeque, fmt};
+use std::fmt;
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_18.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(18));
 
 type MyPoint = Point<usize>;
 
@@ -113,17 +113,7 @@ impl Grid {
     /// ## Returns
     /// * `Option<(usize, Vec<MyPoint>)>` - A tuple containing the number of steps and the path.
     fn find_shortest_path(&self, start: MyPoint, end: MyPoint) -> Option<(usize, Vec<MyPoint>)> {
-        let mut visited = vec![vec![false; self.size]; self.size];
-        let mut queue = VecDeque::new();
-        let mut distances = vec![vec![usize::MAX; self.size]; self.size];
-        let mut prev = vec![vec![None; self.size]; self.size]; // Keep track of previous points
-
-        // Start position
-        queue.push_back(start);
-        visited[start.x][start.y] = true;
-        distances[start.x][start.y] = 0;
-
-        // Possible moves: up, down, left, right
+        // Possible moves: up, down, left, right (this order breaks ties between equal paths)
         let moves = [
             Direction::Up,
             Direction::Down,
@@ -131,47 +121,18 @@ impl Grid {
             Direction::Right,
         ];
 
-        while let Some(current) = queue.pop_front() {
-            if current == end {
-                // Reconstruct path
-                let mut path = Vec::new();
-                let mut curr = current;
-
-                path.push(curr);
-
-                while let Some(previous) = prev[curr.x][curr.y] {
-                    path.push(previous);
-                    curr = previous;
-                }
-
-                path.reverse();
-
-                return Some((distances[current.x][current.y], path));
-            }
-
-            for direction in moves.iter() {
-                let dx = direction.col_delta();
-                let dy = direction.row_delta();
-
-                if let (Some(new_x), Some(new_y)) = (
-                    current.x.checked_add_signed(dx),
-                    current.y.checked_add_signed(dy),
-                ) {
-                    if new_x < self.size
-                        && new_y < self.size
-                        && !visited[new_x][new_y]
-                        && !self.cells[new_x][new_y].is_corrupted
-                    {
-                        visited[new_x][new_y] = true;
-                        distances[new_x][new_y] = distances[current.x][current.y] + 1;
-                        prev[new_x][new_y] = Some(current); // Store the previous point
-                        queue.push_back(Point { x: new_x, y: new_y });
-                    }
-                }
-            }
-        }
+        let result = bfs(
+            start,
+            |&current| {
+                moves
+                    .into_iter()
+                    .filter_map(move |direction| direction.step(current, self.size, self.size))
+                    .filter(|next| !self.cells[next.x][next.y].is_corrupted)
+            },
+            |&current| current == end,
+        )?;
 
-        None
+        Some((result.distance(&end)?, result.path_to(&end)?))
     }
 
     ///
@@ -218,7 +179,7 @@ pub fn response_part_1() {
     // println!("{}", grid);
 
     let shortest_path = grid.find_shortest_path(
-        Point { x: 0, y: 0 },
+        MyPoint::ORIGIN,
         Point {
             x: grid.size - 1,
             y: grid.size - 1,
@@ -272,7 +233,7 @@ pub fn response_part_2() {
 
         // Check if there's still a path to the exit
         let has_path = grid.find_shortest_path(
-            Point { x: 0, y: 0 },
+            MyPoint::ORIGIN,
             Point {
                 x: grid.size - 1,
                 y: grid.size - 1,
@@ -362,7 +323,7 @@ mod tests {
 
         let grid = Grid::new(&first_12_bytes, true);
 
-        let shortest_path = grid.find_shortest_path(Point { x: 0, y: 0 }, Point { x: 6, y: 6 });
+        let shortest_path = grid.find_shortest_path(MyPoint::ORIGIN, Point { x: 6, y: 6 });
         let (steps, path) = shortest_path.unwrap();
 
         println!("{}", grid.display_with_path(&path));
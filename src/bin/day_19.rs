@@ -6,7 +6,7 @@
 use std::{collections::HashMap, str::FromStr};
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_19.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(19));
 
 struct TowelGenerator {
     available_towels: Vec<String>,
@@ -3,16 +3,12 @@
 /// Code for the day 20 of the Advent of Code challenge year 2024
 ///
 // Imports  ==============================================================================  Imports
-use aoc_2024::Point;
+use aoc_2024::{astar, distances_from, manhattan_ball, Point};
 use rayon::prelude::*;
-use std::{
-    cmp::Ordering,
-    collections::{BinaryHeap, HashMap},
-    str::FromStr,
-};
+use std::{collections::HashMap, str::FromStr};
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_20.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(20));
 
 type MyPoint = Point<usize>;
 
@@ -77,6 +73,42 @@ impl Maze {
         .filter(|&p| self.is_walkable(p))
         .collect()
     }
+
+    ///
+    /// # `distance_map`
+    /// Label every cell reachable from `from` with its distance to it.
+    ///
+    /// ## Arguments
+    /// * `from` - The cell to measure distances from
+    ///
+    /// ## Returns
+    /// * `HashMap<MyPoint, usize>` - The distance from `from` of every reachable cell
+    fn distance_map(&self, from: MyPoint) -> HashMap<MyPoint, usize> {
+        distances_from(from, |&p| self.neighbors(p))
+    }
+
+    ///
+    /// # `shortest_path`
+    /// Find the shortest path from the start to the end of the maze.
+    ///
+    /// ## Algorithm
+    /// A* algorithm, guided by the manhattan distance to the end.
+    ///
+    /// ## Returns
+    /// * `Option<(usize, Vec<MyPoint>)>` - The cost of the path and the path itself
+    fn shortest_path(&self) -> Option<(usize, Vec<MyPoint>)> {
+        astar(
+            self.start,
+            |&p| {
+                self.neighbors(p)
+                    .into_iter()
+                    .map(|next| (next, 1))
+                    .collect()
+            },
+            |p| p.manhattan_distance(&self.end),
+            |&p| p == self.end,
+        )
+    }
 }
 
 impl FromStr for Maze {
@@ -110,182 +142,91 @@ impl FromStr for Maze {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct PathState {
-    cost: usize,
-    position: MyPoint,
-}
-
-impl PathState {
-    fn new(cost: usize, position: MyPoint) -> Self {
-        Self { cost, position }
-    }
-}
-
-impl Ord for PathState {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other
-            .cost
-            .cmp(&self.cost)
-            .then_with(|| self.position.x.cmp(&other.position.x))
-            .then_with(|| self.position.y.cmp(&other.position.y))
-    }
-}
-
-impl PartialOrd for PathState {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-struct AStar<'a> {
-    maze: &'a Maze,
-    frontier: BinaryHeap<PathState>,
-    came_from: HashMap<MyPoint, Option<MyPoint>>,
-    cost_so_far: HashMap<MyPoint, usize>,
+///
+/// # `PathFinder`
+/// Distances from the start and to the end of every walkable cell. A cheat from `a` to `b`
+/// takes `from_start[a] + cheat + to_end[b]`, so cheats are measured against the best
+/// time whether the maze is a single track or has branches.
+struct PathFinder {
+    from_start: HashMap<MyPoint, usize>,
+    to_end: HashMap<MyPoint, usize>,
+    best_time: usize,
 }
 
-impl<'a> AStar<'a> {
-    fn new(maze: &'a Maze) -> Self {
-        let mut frontier = BinaryHeap::new();
-        frontier.push(PathState::new(0, maze.start));
-
-        let mut came_from = HashMap::new();
-        let mut cost_so_far = HashMap::new();
-
-        came_from.insert(maze.start, None);
-        cost_so_far.insert(maze.start, 0);
+impl PathFinder {
+    fn new(maze: &Maze) -> Self {
+        let (best_time, _) = maze.shortest_path().expect("the end can't be reached");
+        let from_start = maze.distance_map(maze.start);
+        let to_end = maze.distance_map(maze.end);
 
         Self {
-            maze,
-            frontier,
-            came_from,
-            cost_so_far,
+            from_start,
+            to_end,
+            best_time,
         }
     }
 
     ///
-    /// # `find_path`
-    /// Find the shortest path from the start to the end of the maze.
-    ///
-    /// ## Algorithm
-    /// A* algorithm to find the shortest path from the start to the end of the maze.
-    ///
-    /// ## Returns
-    /// * `Option<(usize, Vec<MyPoint>)>` - The cost of the path and the path itself
-    fn find_path(&mut self) -> Option<(usize, Vec<MyPoint>)> {
-        while let Some(current) = self.frontier.pop() {
-            if current.position == self.maze.end {
-                break;
-            }
-
-            for next in self.maze.neighbors(current.position) {
-                let new_cost = self.cost_so_far[&current.position] + 1;
-
-                if !self.cost_so_far.contains_key(&next) || new_cost < self.cost_so_far[&next] {
-                    self.cost_so_far.insert(next, new_cost);
-
-                    let priority = new_cost + next.manhattan_distance(&self.maze.end);
-
-                    self.frontier.push(PathState::new(priority, next));
-                    self.came_from.insert(next, Some(current.position));
-                }
-            }
-        }
-
-        self.reconstruct_path()
-    }
-
+    /// # `savings_from`
+    /// Time saved by each cheat starting at a given cell, cheats saving nothing being skipped.
     ///
-    /// # `reconstruct_path`
-    /// Reconstruct the path from the start to the end of the maze.
+    /// ## Arguments
+    /// * `cheat_start` - The cell where the cheat starts
+    /// * `max_cheat_time` - The maximum time to cheat
     ///
     /// ## Returns
-    /// * `Option<(usize, Vec<MyPoint>)>` - The cost of the path and the path itself
-    fn reconstruct_path(&self) -> Option<(usize, Vec<MyPoint>)> {
-        let mut path = vec![self.maze.end];
-        let mut current = self.maze.end;
-
-        while current != self.maze.start {
-            if let Some(Some(prev)) = self.came_from.get(&current) {
-                current = *prev;
-
-                path.push(current);
-            } else {
-                return None;
-            }
-        }
-        path.reverse();
-
-        Some((self.cost_so_far[&self.maze.end], path))
-    }
-}
+    /// * `impl Iterator<Item = usize>` - The savings of each cheat
+    fn savings_from(
+        &self,
+        cheat_start: MyPoint,
+        max_cheat_time: usize,
+    ) -> impl Iterator<Item = usize> + '_ {
+        let elapsed = self.from_start[&cheat_start];
+        let center = Point::<i32>::try_from(cheat_start).unwrap();
 
-struct PathFinder {
-    path: Vec<MyPoint>,
-}
+        manhattan_ball(center, max_cheat_time as i32).filter_map(move |(cheat_end, cheat_dist)| {
+            let cheat_end = MyPoint::try_from(cheat_end).ok()?;
+            let time = elapsed + cheat_dist as usize + self.to_end.get(&cheat_end)?;
 
-impl PathFinder {
-    fn new(path: Vec<MyPoint>) -> Self {
-        Self { path }
+            (time < self.best_time).then(|| self.best_time - time)
+        })
     }
 
     ///
     /// # `find_cheats`
-    /// Find the number of possible cheats in the path.
+    /// Find the number of possible cheats in the maze.
     ///
     /// ## Arguments
     /// * `max_cheat_time` - The maximum time to cheat
+    /// * `min_savings` - The minimum time to save
+    ///
+    /// ## Returns
+    /// * `usize` - The number of cheats saving at least `min_savings`
     fn find_cheats(&self, max_cheat_time: usize, min_savings: usize) -> usize {
-        (0..self.path.len())
-            .par_bridge() // Parallelize the loop
-            .map(|start_time| self.find_cheats_from(max_cheat_time, min_savings, start_time))
+        self.from_start
+            .par_iter()
+            .map(|(&cheat_start, _)| {
+                self.savings_from(cheat_start, max_cheat_time)
+                    .filter(|&savings| savings >= min_savings)
+                    .count()
+            })
             .sum()
     }
 
     ///
-    /// # `find_cheats_from`
-    /// Find the number of possible cheats in the path from a given start time.
+    /// # `best_cheat`
+    /// Find the maximum time a single cheat can save.
     ///
     /// ## Arguments
     /// * `max_cheat_time` - The maximum time to cheat
-    /// * `min_savings` - The minimum time to save
-    /// * `start_time` - The start time to find cheats from
     ///
     /// ## Returns
-    /// * `usize` - The number of possible cheats
-    fn find_cheats_from(
-        &self,
-        max_cheat_time: usize,
-        min_savings: usize,
-        start_time: usize,
-    ) -> usize {
-        let mut viable = 0;
-        let cheat_start = self.path[start_time];
-
-        if start_time > self.path.len() - min_savings {
-            return 0;
-        }
-
-        let mut normal_end_time = start_time + min_savings;
-        while normal_end_time < self.path.len() {
-            let cheat_end = self.path[normal_end_time];
-            let cheat_dist = cheat_start.manhattan_distance(&cheat_end);
-
-            if cheat_dist > max_cheat_time {
-                normal_end_time += cheat_dist - max_cheat_time;
-            } else {
-                let cheat_end_time = start_time + cheat_dist;
-                let savings = normal_end_time - cheat_end_time;
-
-                if savings >= min_savings {
-                    viable += 1;
-                }
-
-                normal_end_time += 1;
-            }
-        }
-        viable
+    /// * `Option<usize>` - The best savings, or `None` if no cheat saves any time
+    fn best_cheat(&self, max_cheat_time: usize) -> Option<usize> {
+        self.from_start
+            .par_iter()
+            .filter_map(|(&cheat_start, _)| self.savings_from(cheat_start, max_cheat_time).max())
+            .max()
     }
 }
 
@@ -293,9 +234,8 @@ pub fn response_part_1() {
     println!("Day 19 - Part 1");
     let start = std::time::Instant::now();
 
-    let maze = Maze::from_str(INPUT).unwrap();
-    let (_, normal_path) = AStar::new(&maze).find_path().unwrap();
-    let path_finder = PathFinder::new(normal_path);
+    let maze = Maze::from_str(&INPUT).unwrap();
+    let path_finder = PathFinder::new(&maze);
     let cheats = path_finder.find_cheats(2, 100);
 
     let duration = start.elapsed();
@@ -308,14 +248,18 @@ pub fn response_part_2() {
     println!("Day 19 - Part 2");
     let start = std::time::Instant::now();
 
-    let maze = Maze::from_str(INPUT).unwrap();
-    let (_, normal_path) = AStar::new(&maze).find_path().unwrap();
-    let path_finder = PathFinder::new(normal_path);
+    let maze = Maze::from_str(&INPUT).unwrap();
+    let path_finder = PathFinder::new(&maze);
     let cheats = path_finder.find_cheats(20, 100);
+    let best = path_finder.best_cheat(20);
 
     let duration = start.elapsed();
 
     println!("cheats: {cheats}");
+    // Not in the `label: value` form, so that it isn't taken for an answer
+    if let Some(best) = best {
+        println!("(the best cheat saves {best} picoseconds)");
+    }
     println!("Duration: {duration:?}");
 }
 
@@ -353,4 +297,54 @@ mod tests {
         assert_eq!(maze.start, MyPoint::new(1, 3));
         assert_eq!(maze.end, MyPoint::new(5, 7));
     }
+
+    #[test]
+    fn test_best_cheat() {
+        let maze = Maze::from_str(EXAMPLE_INPUT).unwrap();
+        let path_finder = PathFinder::new(&maze);
+
+        assert_eq!(path_finder.best_cheat(2), Some(64));
+        assert_eq!(path_finder.best_cheat(20), Some(76));
+    }
+
+    #[test]
+    fn test_distance_map() {
+        let maze = Maze::from_str(EXAMPLE_INPUT).unwrap();
+        let distances = maze.distance_map(maze.start);
+        let (cost, path) = maze.shortest_path().unwrap();
+
+        assert_eq!(distances[&maze.start], 0);
+        assert_eq!(distances[&maze.end], 84);
+        assert_eq!(cost, 84);
+        assert_eq!(distances.len(), path.len());
+    }
+
+    #[test]
+    fn test_find_cheats() {
+        let maze = Maze::from_str(EXAMPLE_INPUT).unwrap();
+        let path_finder = PathFinder::new(&maze);
+
+        // 14 + 14 + 2 + 4 + 2 + 3 + 1 + 1 + 1 + 1 + 1 cheats save at least 2 picoseconds
+        assert_eq!(path_finder.find_cheats(2, 2), 44);
+        // 32 + 31 + 29 + 39 + 25 + 23 + 20 + 19 + 12 + 14 + 12 + 22 + 4 + 3 cheats save at least 50
+        assert_eq!(path_finder.find_cheats(20, 50), 285);
+    }
+
+    #[test]
+    fn test_find_cheats_with_branches() {
+        // The lower corridor is a longer way around, cheats into it must not count
+        let maze = Maze::from_str(
+            "\
+#######
+#S...E#
+#.###.#
+#.....#
+#######",
+        )
+        .unwrap();
+        let path_finder = PathFinder::new(&maze);
+
+        assert_eq!(path_finder.best_time, 4);
+        assert_eq!(path_finder.best_cheat(2), None);
+    }
 }
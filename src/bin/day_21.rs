@@ -3,10 +3,11 @@
 /// Code for the day 20 of the Advent of Code challenge year 2024
 ///
 // Imports  ==============================================================================  Imports
+use aoc_2024::parse_char_grid;
 use std::{cmp::Reverse, collections::BinaryHeap, fmt::Display, str::FromStr};
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_21.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(21));
 const NUMERIC_PAD: &str = "789\n456\n123\nX0A";
 const DIRECTIONAL_PAD: &str = "X^A\n<v>";
 
@@ -86,9 +87,7 @@ impl FromStr for KeypadGrid {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let grid: Vec<Vec<char>> = s.lines().map(|line| line.chars().collect()).collect();
-        let height = grid.len();
-        let width = grid[0].len();
+        let (grid, width, height) = parse_char_grid(s);
 
         Ok(KeypadGrid {
             grid,
@@ -153,15 +152,7 @@ impl KeypadGrid {
     /// ## Returns
     /// * `Option<Position>` - The position of the character if found
     fn find_char(&self, c: char) -> Option<Position> {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                if self.grid[y][x] == c {
-                    return Some((x, y));
-                }
-            }
-        }
-
-        None
+        aoc_2024::find_char(&self.grid, c).map(|point| (point.x, point.y))
     }
 }
 
@@ -318,19 +309,33 @@ impl PuzzleSolver {
     }
 }
 
-pub fn response_part_1() {
-    println!("Day 21 - Part 1");
-
-    let start = std::time::Instant::now();
+///
+/// # `solve`
+/// Sum the complexities of all the codes.
+///
+/// ## Arguments
+/// * `input` - The codes, one per line
+/// * `depth` - The number of directional keypads operated by robots
+///
+/// ## Returns
+/// * `usize` - The sum of the complexities of the codes
+fn solve(input: &str, depth: i32) -> usize {
     let solver = PuzzleSolver::new();
+    let press_costs = solver.build_press_costs(depth);
 
-    let press_costs = solver.build_press_costs(2);
-
-    let result: usize = INPUT
+    input
         .trim()
         .lines()
         .map(|line| solver.calculate_code_complexity(press_costs, line))
-        .sum();
+        .sum()
+}
+
+pub fn response_part_1() {
+    println!("Day 21 - Part 1");
+
+    let start = std::time::Instant::now();
+
+    let result = solve(&INPUT, 2);
 
     let duration = start.elapsed();
 
@@ -342,14 +347,7 @@ pub fn response_part_2() {
     println!("Day 21 - Part 2");
     let start = std::time::Instant::now();
 
-    let solver = PuzzleSolver::new();
-    let press_costs = solver.build_press_costs(25);
-
-    let result: usize = INPUT
-        .trim()
-        .lines()
-        .map(|line| solver.calculate_code_complexity(press_costs, line))
-        .sum();
+    let result = solve(&INPUT, 25);
 
     let duration = start.elapsed();
 
@@ -378,4 +376,16 @@ mod tests {
         assert_eq!(grid.find_char('5'), Some((1, 1)));
         assert_eq!(grid.find_char('X'), Some((0, 3)));
     }
+
+    #[test]
+    fn test_solve() {
+        let codes = "\
+029A
+980A
+179A
+456A
+379A";
+
+        assert_eq!(solve(codes, 2), 126384);
+    }
 }
@@ -15,7 +15,7 @@
 use rustc_hash::FxHashMap; // Fast hashmap for better performance
 
 // Variables  =========================================================================== Variables
-const INPUT: &str = include_str!("../../data/inputs/day_22.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(22));
 
 ///
 /// # `PseugoRandomNumberGenerator`
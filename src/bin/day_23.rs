@@ -10,7 +10,7 @@ use std::{
 };
 
 // Constants ============================================================================ Constants
-const INPUT: &str = include_str!("../../data/inputs/day_23.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(23));
 
 // Types ================================================================================== Types
 /// Represents an undirected graph using adjacency lists
@@ -212,35 +212,35 @@ impl Graph {
 }
 
 // Functions ============================================================================ Functions
-pub fn response_part_1() {
+fn response_part_1(graph: &Graph) {
     println!("Day 23 - Part 1");
     let start = std::time::Instant::now();
 
-    let graph: Graph = INPUT.parse().unwrap();
-    let result = graph.find_triads_with_t().len();
+    let triads = graph.find_triads_with_t().len();
 
     let duration = start.elapsed();
 
-    println!("Result: {result}");
-    println!("Duration: {duration:?}");
+    println!("Triads: {triads}");
+    println!("Duration: {duration:?}\n");
 }
 
-pub fn response_part_2() {
+fn response_part_2(graph: &Graph) {
     println!("Day 23 - Part 2");
     let start = std::time::Instant::now();
 
-    let graph: Graph = INPUT.parse().unwrap();
     let max_clique = graph.find_maximum_clique().join(",");
 
     let duration = start.elapsed();
 
-    println!("Result: {max_clique}");
+    println!("Password: {max_clique}");
     println!("Duration: {duration:?}");
 }
 
 fn main() {
-    response_part_1();
-    response_part_2();
+    let graph: Graph = INPUT.parse().unwrap();
+
+    response_part_1(&graph);
+    response_part_2(&graph);
 }
 
 #[cfg(test)]
@@ -312,4 +312,12 @@ td-yn";
 
         assert_eq!(result, "co,de,ka,ta");
     }
+
+    #[test]
+    fn test_solve() {
+        let graph: Graph = TEST_INPUT.parse().unwrap();
+
+        assert_eq!(graph.find_triads_with_t().len(), 7);
+        assert_eq!(graph.find_maximum_clique().join(","), "co,de,ka,ta");
+    }
 }
@@ -9,7 +9,7 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 // Constants ============================================================================ Constants
-const INPUT: &str = include_str!("../../data/inputs/day_24.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(24));
 
 // Types ==================================================================================== Types
 /// Custom error type for circuit parsing and evaluation
@@ -297,6 +297,76 @@ impl Circuit {
         }
     }
 
+    ///
+    /// # `find_gate`
+    /// Finds the output wire of the gate of the given kind wired to both inputs
+    ///
+    /// ## Arguments
+    /// * `kind` - Gate kind, as written in the input ("XOR", "AND" or "OR")
+    /// * `a`, `b` - The two input wires, in any order
+    ///
+    /// ## Returns
+    /// * `Option<String>` - The output wire of the matching gate, if any
+    fn find_gate(&self, kind: &str, a: &str, b: &str) -> Option<String> {
+        self.gates.iter().find_map(|gate| {
+            let (gate_kind, in1, in2, out) = match gate {
+                Gate::Xor(in1, in2, out) => ("XOR", in1, in2, out),
+                Gate::And(in1, in2, out) => ("AND", in1, in2, out),
+                Gate::Or(in1, in2, out) => ("OR", in1, in2, out),
+            };
+
+            (gate_kind == kind && ((in1 == a && in2 == b) || (in1 == b && in2 == a)))
+                .then(|| out.clone())
+        })
+    }
+
+    ///
+    /// # `trace_carry_chain`
+    /// Follows the carry wires of a ripple-carry adder, bit by bit
+    ///
+    /// ## Algorithm
+    /// 1. The bit 0 carry is `x00 AND y00`
+    /// 2. For each next bit `i`, with `c` the previous carry:
+    ///    - `s = xi XOR yi` and `a = xi AND yi`
+    ///    - `t = s AND c`
+    ///    - the new carry is `a OR t`
+    /// 3. Stop as soon as one of these gates can't be found
+    ///
+    /// ## Returns
+    /// * `Result<Vec<String>, (usize, Vec<String>)>` - The carry wire of each level, or the
+    ///   bit where the pattern breaks along with the carry wires of the levels below it
+    fn trace_carry_chain(&self) -> Result<Vec<String>, (usize, Vec<String>)> {
+        let num_bits = self.inputs.keys().filter(|k| k.starts_with('x')).count();
+        let mut chain = Vec::new();
+
+        let Some(mut carry) = self.find_gate("AND", "x00", "y00") else {
+            return Err((0, chain));
+        };
+        chain.push(carry.clone());
+
+        for i in 1..num_bits {
+            let x = format!("x{i:02}");
+            let y = format!("y{i:02}");
+
+            let next_carry = self.find_gate("XOR", &x, &y).and_then(|sum| {
+                let and = self.find_gate("AND", &x, &y)?;
+                let transfer = self.find_gate("AND", &sum, &carry)?;
+
+                self.find_gate("OR", &and, &transfer)
+            });
+
+            match next_carry {
+                Some(next_carry) => {
+                    carry = next_carry;
+                    chain.push(carry.clone());
+                }
+                None => return Err((i, chain)),
+            }
+        }
+
+        Ok(chain)
+    }
+
     /// Finds the four pairs of gates that need to be swapped
     pub fn find_broken_gates(&self) -> Option<Vec<String>> {
         let candidates = self.get_swappable_gates();
@@ -332,7 +402,7 @@ pub fn response_part_1() {
     let start = std::time::Instant::now();
 
     // Parse the circuit from input
-    let circuit = Circuit::from_str(INPUT).unwrap();
+    let circuit = Circuit::from_str(&INPUT).unwrap();
 
     // Get all outputs z00 through z63 and combine them into a u64
     let mut result: u64 = 0;
@@ -353,7 +423,7 @@ pub fn response_part_2() {
     println!("Day 24 - Part 2");
     let start = std::time::Instant::now();
 
-    let circuit = Circuit::from_str(INPUT).unwrap();
+    let circuit = Circuit::from_str(&INPUT).unwrap();
 
     // Find and fix the broken gates
     match circuit.find_broken_gates() {
@@ -364,6 +434,11 @@ pub fn response_part_2() {
         None => println!("No solution found!"),
     }
 
+    // Not in the `label: value` form, so that it isn't taken for an answer
+    if let Err((bit, _)) = circuit.trace_carry_chain() {
+        println!("(the carry chain breaks at bit {bit})");
+    }
+
     let duration = start.elapsed();
     println!("Duration: {duration:?}");
 }
@@ -444,6 +519,47 @@ a01 OR x00 -> z01";
         ));
     }
 
+    #[test]
+    fn test_trace_carry_chain() {
+        let input = "\
+x00: 1
+x01: 0
+x02: 1
+y00: 1
+y01: 1
+y02: 0
+
+x00 XOR y00 -> z00
+x00 AND y00 -> c00
+x01 XOR y01 -> s01
+x01 AND y01 -> a01
+s01 XOR c00 -> z01
+s01 AND c00 -> t01
+a01 OR t01 -> c01
+x02 XOR y02 -> s02
+x02 AND y02 -> a02
+s02 XOR c01 -> z02
+c01 AND s02 -> t02
+t02 OR a02 -> z03";
+
+        let circuit = Circuit::from_str(input).unwrap();
+        assert_eq!(
+            circuit.trace_carry_chain(),
+            Ok(vec![
+                "c00".to_string(),
+                "c01".to_string(),
+                "z03".to_string()
+            ])
+        );
+
+        // Swapping the outputs of the bit 1 gates breaks the chain at that level
+        let broken = circuit.with_swapped_outputs(&[("s01".to_string(), "a01".to_string())]);
+        assert_eq!(
+            broken.trace_carry_chain(),
+            Err((1, vec!["c00".to_string()]))
+        );
+    }
+
     #[test]
     fn test_empty_input() {
         let empty = "";
@@ -11,7 +11,7 @@ use itertools::{Either, Itertools};
 
 // Constants  =========================================================================  Constants
 /// Input file containing lock and key schematics
-const INPUT: &str = include_str!("../../data/inputs/day_25.txt");
+static INPUT: std::sync::LazyLock<String> = std::sync::LazyLock::new(|| aoc_2024::load_input(25));
 
 /// Maximum height of the schematic grid (used for overlap checking)
 const GRID_HEIGHT: u8 = 7;
@@ -120,7 +120,7 @@ pub fn response_part_1() {
     println!("Day 25 - Part 1");
     let start = std::time::Instant::now();
 
-    let schematics = Schematics::from_str(INPUT);
+    let schematics = Schematics::from_str(&INPUT);
     let count = schematics.count_match();
 
     let duration = start.elapsed();
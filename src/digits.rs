@@ -0,0 +1,77 @@
+///
+/// # `num_digits`
+/// Number of decimal digits of a number, without going through floats.
+///
+/// ## Arguments
+/// * `n` - The number
+///
+/// ## Returns
+/// * `u32` - The number of digits, 1 for 0
+pub fn num_digits(n: u64) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+
+///
+/// # `split_even`
+/// Splits a number with an even number of digits into its left and right halves.
+///
+/// ## Arguments
+/// * `n` - The number
+///
+/// ## Returns
+/// * `Option<(u64, u64)>` - The two halves (`1000` gives `(10, 0)`), `None` for an odd digit count
+pub fn split_even(n: u64) -> Option<(u64, u64)> {
+    let digits = num_digits(n);
+    if !digits.is_multiple_of(2) {
+        return None;
+    }
+
+    let power = 10_u64.pow(digits / 2);
+
+    Some((n / power, n % power))
+}
+
+///
+/// # `concat`
+/// Concatenates the decimal digits of two numbers.
+///
+/// ## Arguments
+/// * `a` - The left number
+/// * `b` - The right number
+///
+/// ## Returns
+/// * `u64` - `a` followed by the digits of `b`, e.g. `concat(12, 345) == 12345`
+pub fn concat(a: u64, b: u64) -> u64 {
+    a * 10_u64.pow(num_digits(b)) + b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_digits() {
+        assert_eq!(num_digits(0), 1);
+        assert_eq!(num_digits(9), 1);
+        assert_eq!(num_digits(10), 2);
+        assert_eq!(num_digits(999_999_999_999_999), 15);
+        assert_eq!(num_digits(1_000_000_000_000_000), 16);
+        assert_eq!(num_digits(u64::MAX), 20);
+    }
+
+    #[test]
+    fn test_split_even() {
+        assert_eq!(split_even(1000), Some((10, 0)));
+        assert_eq!(split_even(253000), Some((253, 0)));
+        assert_eq!(split_even(17), Some((1, 7)));
+        assert_eq!(split_even(0), None);
+        assert_eq!(split_even(125), None);
+    }
+
+    #[test]
+    fn test_concat() {
+        assert_eq!(concat(12, 345), 12345);
+        assert_eq!(concat(15, 6), 156);
+        assert_eq!(concat(1, 0), 10);
+    }
+}
@@ -1,6 +1,12 @@
-use std::ops::Add;
+use crate::Point;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::{Add, AddAssign};
 
 #[derive(Debug, PartialEq, Clone, Copy, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Direction {
     Up,
     Down,
@@ -15,9 +21,11 @@ pub enum Direction {
 #[derive(Debug)]
 pub enum DirectionError {
     InvalidDirection,
+    // Character at `index` (in chars, whitespace included) of a move string
+    InvalidMove { index: usize, ch: char },
 }
 
-// direction to (y, x)
+// direction to (y, x), i.e. (row delta, column delta): Up is (-1, 0), Right is (0, 1)
 impl From<Direction> for (isize, isize) {
     fn from(d: Direction) -> Self {
         match d {
@@ -33,12 +41,20 @@ impl From<Direction> for (isize, isize) {
     }
 }
 
+// Same (y, x) contract as the isize version
+impl From<Direction> for (i32, i32) {
+    fn from(d: Direction) -> Self {
+        let (dy, dx): (isize, isize) = d.into();
+        (dy as i32, dx as i32)
+    }
+}
+
 // (y, x) + direction
 impl Add<Direction> for (isize, isize) {
     type Output = (isize, isize);
 
     fn add(self, d: Direction) -> Self::Output {
-        let (dy, dx) = d.into(); // Changed from (dx, dy)
+        let (dy, dx): (isize, isize) = d.into(); // Changed from (dx, dy)
         (self.0 + dy, self.1 + dx) // Changed from (self.0 + dx, self.1 + dy)
     }
 }
@@ -47,7 +63,7 @@ impl Add<Direction> for (usize, usize) {
     type Output = (usize, usize);
 
     fn add(self, d: Direction) -> Self::Output {
-        let (dy, dx) = d.into(); // Changed from (dx, dy)
+        let (dy, dx): (isize, isize) = d.into(); // Changed from (dx, dy)
 
         // wrapping_add to avoid panics
         (
@@ -57,19 +73,128 @@ impl Add<Direction> for (usize, usize) {
     }
 }
 
-impl From<char> for Direction {
-    fn from(value: char) -> Self {
+// Wraps like `Add`: stepping Up from row 0 or Left from column 0 gives `usize::MAX`
+// instead of panicking, which any `< width`/`< height` check or `.get()` rejects
+impl AddAssign<Direction> for (usize, usize) {
+    fn add_assign(&mut self, d: Direction) {
+        *self = *self + d;
+    }
+}
+
+// Cardinals use the same arrows `TryFrom<char>` accepts. Diagonals use the
+// unicode arrows `↖↗↙↘` since `\` and `/` would not tell e.g. UpLeft from DownRight.
+impl From<Direction> for char {
+    fn from(d: Direction) -> Self {
+        match d {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+            Direction::UpLeft => '↖',
+            Direction::UpRight => '↗',
+            Direction::DownLeft => '↙',
+            Direction::DownRight => '↘',
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+// Arrows (`^v<>`), lowercase WASD or uppercase compass letters (`NSEW`).
+// This replaces the old `From<char>`, which panicked on anything else:
+// a `From` impl would conflict with the blanket `TryFrom` from core.
+impl TryFrom<char> for Direction {
+    type Error = DirectionError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '^' => Self::Up,
-            '>' => Self::Right,
-            '<' => Self::Left,
-            'v' => Self::Down,
-            _ => panic!(),
+            '^' | 'w' | 'N' => Ok(Self::Up),
+            '>' | 'd' | 'E' => Ok(Self::Right),
+            '<' | 'a' | 'W' => Ok(Self::Left),
+            'v' | 's' | 'S' => Ok(Self::Down),
+            _ => Err(DirectionError::InvalidDirection),
         }
     }
 }
 
 impl Direction {
+    ///
+    /// # `all`
+    /// Every direction, cardinals first.
+    ///
+    /// ## Returns
+    /// * `[Direction; 8]` - The 4 cardinals, then the 4 diagonals
+    pub fn all() -> [Direction; 8] {
+        [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+            Direction::UpLeft,
+            Direction::UpRight,
+            Direction::DownLeft,
+            Direction::DownRight,
+        ]
+    }
+
+    pub fn cardinals() -> [Direction; 4] {
+        [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ]
+    }
+
+    pub fn diagonals() -> [Direction; 4] {
+        [
+            Direction::UpLeft,
+            Direction::UpRight,
+            Direction::DownLeft,
+            Direction::DownRight,
+        ]
+    }
+
+    ///
+    /// # `is_cardinal`
+    /// Whether the direction is one of Up, Down, Left and Right.
+    ///
+    /// ## Returns
+    /// * `bool` - `true` for a cardinal, `false` for a diagonal
+    pub fn is_cardinal(&self) -> bool {
+        matches!(
+            self,
+            Direction::Up | Direction::Down | Direction::Left | Direction::Right
+        )
+    }
+
+    ///
+    /// # `is_diagonal`
+    /// Whether the direction combines a vertical and a horizontal step.
+    ///
+    /// ## Returns
+    /// * `bool` - `true` for a diagonal, `false` for a cardinal
+    pub fn is_diagonal(&self) -> bool {
+        !self.is_cardinal()
+    }
+
+    ///
+    /// # `perpendicular`
+    /// The two directions at right angles.
+    ///
+    /// ## Returns
+    /// * `[Direction; 2]` - The counterclockwise one first, then the clockwise one
+    pub fn perpendicular(&self) -> [Direction; 2] {
+        [
+            self.turn_counterclockwise().turn_counterclockwise(),
+            self.turn_clockwise().turn_clockwise(),
+        ]
+    }
+
     pub fn row_delta(&self) -> isize {
         match self {
             Direction::Up | Direction::UpLeft | Direction::UpRight => -1,
@@ -86,6 +211,16 @@ impl Direction {
         }
     }
 
+    ///
+    /// # `unit_vector`
+    /// Unit step as a point.
+    ///
+    /// ## Returns
+    /// * `Point<i32>` - The step, x following the column and y the row
+    pub fn unit_vector(&self) -> Point<i32> {
+        Point::new(self.col_delta() as i32, self.row_delta() as i32)
+    }
+
     pub fn from_points(
         from: (isize, isize),
         to: (isize, isize),
@@ -112,7 +247,76 @@ impl Direction {
         }
     }
 
+    ///
+    /// # `turn_clockwise`
+    /// 45° clockwise turn, going through all 8 directions.
+    ///
+    /// ## Returns
+    /// * `Direction` - The next direction clockwise
     pub fn turn_clockwise(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::UpRight,
+            Direction::UpRight => Direction::Right,
+            Direction::Right => Direction::DownRight,
+            Direction::DownRight => Direction::Down,
+            Direction::Down => Direction::DownLeft,
+            Direction::DownLeft => Direction::Left,
+            Direction::Left => Direction::UpLeft,
+            Direction::UpLeft => Direction::Up,
+        }
+    }
+
+    ///
+    /// # `turn_counterclockwise`
+    /// 45° counterclockwise turn, going through all 8 directions.
+    ///
+    /// ## Returns
+    /// * `Direction` - The next direction counterclockwise
+    pub fn turn_counterclockwise(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::UpLeft,
+            Direction::UpLeft => Direction::Left,
+            Direction::Left => Direction::DownLeft,
+            Direction::DownLeft => Direction::Down,
+            Direction::Down => Direction::DownRight,
+            Direction::DownRight => Direction::Right,
+            Direction::Right => Direction::UpRight,
+            Direction::UpRight => Direction::Up,
+        }
+    }
+
+    ///
+    /// # `octants_clockwise`
+    /// The 8 directions in clockwise order, starting from this one.
+    ///
+    /// ## Returns
+    /// * `impl Iterator<Item = Direction>` - This direction, then each 45° clockwise turn
+    pub fn octants_clockwise(&self) -> impl Iterator<Item = Direction> {
+        std::iter::successors(Some(*self), |d| Some(d.turn_clockwise())).take(8)
+    }
+
+    ///
+    /// # `rotate`
+    /// Rotates by multiples of 45°.
+    ///
+    /// ## Arguments
+    /// * `eighths` - The number of 45° turns, clockwise when positive and counterclockwise when negative
+    ///
+    /// ## Returns
+    /// * `Direction` - The rotated direction
+    pub fn rotate(&self, eighths: i32) -> Direction {
+        self.octants_clockwise()
+            .nth(eighths.rem_euclid(8) as usize)
+            .unwrap()
+    }
+
+    ///
+    /// # `turn_right_90`
+    /// 90° clockwise turn, only for the 4 cardinal directions.
+    ///
+    /// ## Returns
+    /// * `Direction` - The cardinal to the right, panics on a diagonal
+    pub fn turn_right_90(&self) -> Direction {
         match self {
             Direction::Up => Direction::Right,
             Direction::Right => Direction::Down,
@@ -122,7 +326,13 @@ impl Direction {
         }
     }
 
-    pub fn turn_counterclockwise(&self) -> Direction {
+    ///
+    /// # `turn_left_90`
+    /// 90° counterclockwise turn, only for the 4 cardinal directions.
+    ///
+    /// ## Returns
+    /// * `Direction` - The cardinal to the left, panics on a diagonal
+    pub fn turn_left_90(&self) -> Direction {
         match self {
             Direction::Up => Direction::Left,
             Direction::Left => Direction::Down,
@@ -132,6 +342,81 @@ impl Direction {
         }
     }
 
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::UpLeft => Direction::DownRight,
+            Direction::UpRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpRight,
+            Direction::DownRight => Direction::UpLeft,
+        }
+    }
+
+    ///
+    /// # `reflect_horizontal`
+    /// Flips the left/right component, Up and Down are unchanged.
+    ///
+    /// ## Returns
+    /// * `Direction` - The mirrored direction
+    pub fn reflect_horizontal(&self) -> Direction {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::UpLeft => Direction::UpRight,
+            Direction::UpRight => Direction::UpLeft,
+            Direction::DownLeft => Direction::DownRight,
+            Direction::DownRight => Direction::DownLeft,
+            Direction::Up | Direction::Down => *self,
+        }
+    }
+
+    ///
+    /// # `reflect_vertical`
+    /// Flips the up/down component, Left and Right are unchanged.
+    ///
+    /// ## Returns
+    /// * `Direction` - The mirrored direction
+    pub fn reflect_vertical(&self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::UpLeft => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpLeft,
+            Direction::UpRight => Direction::DownRight,
+            Direction::DownRight => Direction::UpRight,
+            Direction::Left | Direction::Right => *self,
+        }
+    }
+
+    ///
+    /// # `step`
+    /// Neighbor of a cell in this direction, within a grid.
+    ///
+    /// ## Arguments
+    /// * `from` - The cell to step from
+    /// * `width` - The width of the grid
+    /// * `height` - The height of the grid
+    ///
+    /// ## Returns
+    /// * `Option<Point<usize>>` - The neighbor, `None` if it leaves the `width` x `height` grid
+    pub fn step(&self, from: Point<usize>, width: usize, height: usize) -> Option<Point<usize>> {
+        from.checked_step(*self)
+            .filter(|next| next.in_bounds(width, height))
+    }
+
+    ///
+    /// # `turn_180`
+    /// Same as `opposite`, reads better next to the other turns.
+    ///
+    /// ## Returns
+    /// * `Direction` - The opposite direction
+    pub fn turn_180(&self) -> Direction {
+        self.opposite()
+    }
+
     pub fn move_forward(&self, pos: (usize, usize)) -> Option<(usize, usize)> {
         match self {
             Direction::Up => Some((pos.0.checked_sub(1)?, pos.1)),
@@ -142,3 +427,348 @@ impl Direction {
         }
     }
 }
+
+///
+/// # `parse_moves`
+/// Parses a string of moves (e.g. `"^^<v>"`), ignoring newlines and any other whitespace.
+///
+/// ## Arguments
+/// * `s` - The moves, in any format `TryFrom<char>` accepts
+///
+/// ## Returns
+/// * `Result<Vec<Direction>, DirectionError>` - The moves, or `InvalidMove` for the first bad character
+pub fn parse_moves(s: &str) -> Result<Vec<Direction>, DirectionError> {
+    s.chars()
+        .enumerate()
+        .filter(|(_, ch)| !ch.is_whitespace())
+        .map(|(index, ch)| {
+            Direction::try_from(ch).map_err(|_| DirectionError::InvalidMove { index, ch })
+        })
+        .collect()
+}
+
+///
+/// # `reverse_path`
+/// The moves that walk `path` backwards, back to where it started.
+///
+/// ## Arguments
+/// * `path` - The moves to undo
+///
+/// ## Returns
+/// * `Vec<Direction>` - The moves in reverse order, each turned around
+pub fn reverse_path(path: &[Direction]) -> Vec<Direction> {
+    path.iter().rev().map(Direction::turn_180).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opposite() {
+        assert_eq!(Direction::Up.opposite(), Direction::Down);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::UpLeft.opposite(), Direction::DownRight);
+        assert_eq!(Direction::DownLeft.opposite(), Direction::UpRight);
+
+        for d in Direction::all() {
+            assert_ne!(d.opposite(), d);
+            assert_eq!(d.opposite().opposite(), d);
+        }
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Direction::Up.turn_clockwise(), Direction::UpRight);
+        assert_eq!(Direction::Up.turn_counterclockwise(), Direction::UpLeft);
+
+        for d in Direction::all() {
+            let mut turned = d;
+            for _ in 0..8 {
+                turned = turned.turn_clockwise();
+            }
+            assert_eq!(turned, d);
+            assert_eq!(d.turn_clockwise().turn_counterclockwise(), d);
+            assert_eq!(
+                d.turn_clockwise()
+                    .turn_clockwise()
+                    .turn_clockwise()
+                    .turn_clockwise(),
+                d.opposite()
+            );
+        }
+    }
+
+    #[test]
+    fn test_octants_clockwise() {
+        assert_eq!(
+            Direction::Up.octants_clockwise().collect::<Vec<_>>(),
+            vec![
+                Direction::Up,
+                Direction::UpRight,
+                Direction::Right,
+                Direction::DownRight,
+                Direction::Down,
+                Direction::DownLeft,
+                Direction::Left,
+                Direction::UpLeft,
+            ]
+        );
+        assert_eq!(
+            Direction::Left.octants_clockwise().nth(1),
+            Some(Direction::UpLeft)
+        );
+    }
+
+    #[test]
+    fn test_turns_90() {
+        assert_eq!(Direction::Up.turn_right_90(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left_90(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right_90(), Direction::Up);
+        assert_eq!(Direction::Right.turn_left_90(), Direction::Up);
+    }
+
+    #[test]
+    fn test_direction_sets() {
+        assert_eq!(Direction::all().len(), 8);
+        assert_eq!(Direction::cardinals().len(), 4);
+        assert_eq!(Direction::diagonals().len(), 4);
+
+        let cardinals = Direction::cardinals();
+        for d in [
+            Direction::Up,
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+        ] {
+            assert!(cardinals.contains(&d));
+        }
+
+        for d in Direction::all() {
+            assert_ne!(
+                Direction::cardinals().contains(&d),
+                Direction::diagonals().contains(&d)
+            );
+        }
+    }
+
+    #[test]
+    fn test_unit_vector() {
+        let expected = [
+            (Direction::Up, Point::new(0, -1)),
+            (Direction::Down, Point::new(0, 1)),
+            (Direction::Left, Point::new(-1, 0)),
+            (Direction::Right, Point::new(1, 0)),
+            (Direction::UpLeft, Point::new(-1, -1)),
+            (Direction::UpRight, Point::new(1, -1)),
+            (Direction::DownLeft, Point::new(-1, 1)),
+            (Direction::DownRight, Point::new(1, 1)),
+        ];
+
+        for (d, point) in expected {
+            assert_eq!(d.unit_vector(), point);
+        }
+    }
+
+    #[test]
+    fn test_try_from_char() {
+        for (chars, d) in [
+            ("^wN", Direction::Up),
+            ("vsS", Direction::Down),
+            ("<aW", Direction::Left),
+            (">dE", Direction::Right),
+        ] {
+            for c in chars.chars() {
+                assert_eq!(Direction::try_from(c).unwrap(), d);
+            }
+        }
+
+        assert!(matches!(
+            Direction::try_from('x'),
+            Err(DirectionError::InvalidDirection)
+        ));
+    }
+
+    #[test]
+    fn test_reflections() {
+        assert_eq!(Direction::UpRight.reflect_horizontal(), Direction::UpLeft);
+        assert_eq!(Direction::Right.reflect_horizontal(), Direction::Left);
+        assert_eq!(Direction::Up.reflect_horizontal(), Direction::Up);
+
+        assert_eq!(Direction::UpRight.reflect_vertical(), Direction::DownRight);
+        assert_eq!(Direction::Up.reflect_vertical(), Direction::Down);
+        assert_eq!(Direction::Left.reflect_vertical(), Direction::Left);
+
+        for d in Direction::all() {
+            assert_eq!(d.reflect_horizontal().reflect_horizontal(), d);
+            assert_eq!(d.reflect_vertical().reflect_vertical(), d);
+            assert_eq!(d.reflect_horizontal().reflect_vertical(), d.opposite());
+        }
+    }
+
+    #[test]
+    fn test_to_char() {
+        assert_eq!(char::from(Direction::Up), '^');
+        assert_eq!(char::from(Direction::Down), 'v');
+        assert_eq!(char::from(Direction::Left), '<');
+        assert_eq!(char::from(Direction::Right), '>');
+
+        assert_eq!(char::from(Direction::UpLeft), '↖');
+        assert_eq!(char::from(Direction::UpRight), '↗');
+        assert_eq!(char::from(Direction::DownLeft), '↙');
+        assert_eq!(char::from(Direction::DownRight), '↘');
+
+        assert_eq!(Direction::Right.to_string(), ">");
+        for d in Direction::cardinals() {
+            assert_eq!(Direction::try_from(char::from(d)).unwrap(), d);
+        }
+    }
+
+    #[test]
+    fn test_cardinal_diagonal() {
+        for d in Direction::all() {
+            assert_eq!(d.is_cardinal(), Direction::cardinals().contains(&d));
+            assert_eq!(d.is_diagonal(), Direction::diagonals().contains(&d));
+            assert_ne!(d.is_cardinal(), d.is_diagonal());
+        }
+    }
+
+    #[test]
+    fn test_perpendicular() {
+        assert_eq!(
+            Direction::Up.perpendicular(),
+            [Direction::Left, Direction::Right]
+        );
+        assert_eq!(
+            Direction::Left.perpendicular(),
+            [Direction::Down, Direction::Up]
+        );
+    }
+
+    #[test]
+    fn test_into_deltas() {
+        let expected = [
+            (Direction::Up, (-1, 0)),
+            (Direction::Down, (1, 0)),
+            (Direction::Left, (0, -1)),
+            (Direction::Right, (0, 1)),
+            (Direction::UpLeft, (-1, -1)),
+            (Direction::UpRight, (-1, 1)),
+            (Direction::DownLeft, (1, -1)),
+            (Direction::DownRight, (1, 1)),
+        ];
+
+        for (d, (dy, dx)) in expected {
+            assert_eq!(<(i32, i32)>::from(d), (dy, dx));
+            assert_eq!(<(isize, isize)>::from(d), (dy as isize, dx as isize));
+        }
+    }
+
+    #[test]
+    fn test_row_col_deltas() {
+        let expected = [
+            (Direction::Up, -1, 0),
+            (Direction::Down, 1, 0),
+            (Direction::Left, 0, -1),
+            (Direction::Right, 0, 1),
+            (Direction::UpLeft, -1, -1),
+            (Direction::UpRight, -1, 1),
+            (Direction::DownLeft, 1, -1),
+            (Direction::DownRight, 1, 1),
+        ];
+
+        for (d, row, col) in expected {
+            assert_eq!(d.row_delta(), row, "row_delta of {d:?}");
+            assert_eq!(d.col_delta(), col, "col_delta of {d:?}");
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        assert_eq!(Direction::Up.step(Point::new(0, 0), 3, 3), None);
+        assert_eq!(Direction::Right.step(Point::new(2, 1), 3, 3), None);
+        assert_eq!(Direction::Down.step(Point::new(1, 2), 3, 3), None);
+        assert_eq!(
+            Direction::DownRight.step(Point::new(1, 1), 3, 3),
+            Some(Point::new(2, 2))
+        );
+        assert_eq!(
+            Direction::Left.step(Point::new(1, 1), 3, 3),
+            Some(Point::new(0, 1))
+        );
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(Direction::Up.rotate(2), Direction::Right);
+        assert_eq!(Direction::Up.rotate(-1), Direction::UpLeft);
+        assert_eq!(Direction::Left.rotate(-10), Direction::Down);
+
+        for d in Direction::all() {
+            assert_eq!(d.rotate(8), d);
+            assert_eq!(d.rotate(0), d);
+            assert_eq!(d.rotate(4), d.opposite());
+            assert_eq!(d.rotate(1), d.turn_clockwise());
+            assert_eq!(d.rotate(-1), d.turn_counterclockwise());
+        }
+    }
+
+    #[test]
+    fn test_usize_tuple_add_assign() {
+        let mut pos = (1_usize, 1_usize);
+        pos += Direction::Down;
+        assert_eq!(pos, (2, 1));
+        pos += Direction::Right;
+        assert_eq!(pos, (2, 2));
+        assert_eq!(pos, (1_usize, 1_usize) + Direction::DownRight);
+
+        let grid = [[0; 3]; 3];
+        let mut edge = (0_usize, 0_usize);
+        edge += Direction::Up;
+        assert_eq!(edge, (usize::MAX, 0));
+        assert!(grid.get(edge.0).is_none());
+
+        let mut edge = (0_usize, 0_usize);
+        edge += Direction::Left;
+        assert_eq!(edge, (0, usize::MAX));
+        assert!(grid[edge.0].get(edge.1).is_none());
+    }
+
+    #[test]
+    fn test_parse_moves() {
+        assert_eq!(
+            parse_moves("^^<v>\n><").unwrap(),
+            vec![
+                Direction::Up,
+                Direction::Up,
+                Direction::Left,
+                Direction::Down,
+                Direction::Right,
+                Direction::Right,
+                Direction::Left,
+            ]
+        );
+        assert!(parse_moves("").unwrap().is_empty());
+
+        assert!(matches!(
+            parse_moves("^<\nvx>"),
+            Err(DirectionError::InvalidMove { index: 4, ch: 'x' })
+        ));
+    }
+
+    #[test]
+    fn test_reverse_path() {
+        assert_eq!(
+            reverse_path(&[Direction::Up, Direction::Right]),
+            vec![Direction::Left, Direction::Down]
+        );
+        assert!(reverse_path(&[]).is_empty());
+
+        for d in Direction::all() {
+            assert_eq!(d.turn_180(), d.rotate(4));
+        }
+
+        let path = parse_moves("^^>v<<").unwrap();
+        assert_eq!(reverse_path(&reverse_path(&path)), path);
+    }
+}
@@ -0,0 +1,557 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+///
+/// # `BfsResult`
+/// What a breadth-first search explored before reaching its goal.
+#[derive(Debug, Clone)]
+pub struct BfsResult<N> {
+    goal: N,
+    distances: HashMap<N, usize>,
+    parents: HashMap<N, N>,
+}
+
+impl<N: Hash + Eq + Clone> BfsResult<N> {
+    ///
+    /// # `goal`
+    /// The first node found that satisfied the goal predicate.
+    pub fn goal(&self) -> &N {
+        &self.goal
+    }
+
+    ///
+    /// # `distance`
+    /// Number of steps from the start to a node.
+    ///
+    /// ## Arguments
+    /// * `node` - The node to look up
+    ///
+    /// ## Returns
+    /// * `Option<usize>` - The distance, `None` if the node wasn't reached
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    ///
+    /// # `path_to`
+    /// Rebuilds a shortest path from the start to `node`.
+    ///
+    /// ## Arguments
+    /// * `node` - The node to reach
+    ///
+    /// ## Returns
+    /// * `Option<Vec<N>>` - The nodes from the start to `node`, both included, `None` if it wasn't reached
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+
+        let mut path = vec![node.clone()];
+        let mut current = node;
+        while let Some(parent) = self.parents.get(current) {
+            path.push(parent.clone());
+            current = parent;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+///
+/// # `MinState`
+/// Turns `BinaryHeap` into a min-heap on the priority `P`, carrying a state `T` along.
+/// Only `P` is used for ordering, so two states with the same priority compare as `Equal`
+/// even when they aren't `==`: ties pop in an unspecified order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MinState<P: Ord, T>(pub P, pub T);
+
+impl<P: Ord, T: Eq> Ord for MinState<P, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<P: Ord, T: Eq> PartialOrd for MinState<P, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+///
+/// # `bfs`
+/// Breadth-first search from `start` until a node satisfies `is_goal`.
+///
+/// ## Arguments
+/// * `start` - The starting node
+/// * `neighbors` - The nodes reachable in one step from a node
+/// * `is_goal` - Whether a node ends the search
+///
+/// ## Returns
+/// * `Option<BfsResult<N>>` - The explored distances and paths, `None` if no goal is reachable
+pub fn bfs<N, I>(
+    start: N,
+    neighbors: impl Fn(&N) -> I,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<BfsResult<N>>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut parents = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(current) = queue.pop_front() {
+        if is_goal(&current) {
+            return Some(BfsResult {
+                goal: current,
+                distances,
+                parents,
+            });
+        }
+
+        let distance = distances[&current];
+        for next in neighbors(&current) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                parents.insert(next.clone(), current.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+///
+/// # `distances_from`
+/// Breadth-first search over everything reachable from `start`.
+///
+/// ## Arguments
+/// * `start` - The starting node
+/// * `neighbors` - The nodes reachable in one step from a node
+///
+/// ## Returns
+/// * `HashMap<N, usize>` - The number of steps from `start` to every reachable node
+pub fn distances_from<N, I>(start: N, neighbors: impl Fn(&N) -> I) -> HashMap<N, usize>
+where
+    N: Hash + Eq + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[&current];
+        for next in neighbors(&current) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+///
+/// # `dijkstra`
+/// Cheapest path from `start` to the first node satisfying `is_goal`.
+///
+/// ## Arguments
+/// * `start` - The starting node
+/// * `neighbors` - The nodes reachable from a node, with the cost of the move
+/// * `is_goal` - Whether a node ends the search
+///
+/// ## Returns
+/// * `Option<(usize, Vec<N>)>` - The total cost and the path from `start` to the goal, both included,
+///   `None` if no goal is reachable
+pub fn dijkstra<N>(
+    start: N,
+    neighbors: impl Fn(&N) -> Vec<(N, usize)>,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Hash + Eq + Clone,
+{
+    astar(start, neighbors, |_| 0, is_goal)
+}
+
+///
+/// # `astar`
+/// A* search: Dijkstra guided towards the goal by a heuristic.
+/// The heuristic must never overestimate the remaining cost for the path to be the cheapest.
+///
+/// ## Arguments
+/// * `start` - The starting node
+/// * `neighbors` - The nodes reachable from a node, with the cost of the move
+/// * `heuristic` - Estimated cost from a node to the goal
+/// * `is_goal` - Whether a node ends the search
+///
+/// ## Returns
+/// * `Option<(usize, Vec<N>)>` - The total cost and the path from `start` to the goal, both included,
+///   `None` if no goal is reachable
+pub fn astar<N>(
+    start: N,
+    neighbors: impl Fn(&N) -> Vec<(N, usize)>,
+    heuristic: impl Fn(&N) -> usize,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Hash + Eq + Clone,
+{
+    // Nodes aren't required to be `Ord`, so the heap holds indexes into `nodes`, which also
+    // break ties between equal priorities in favour of the node pushed first
+    let mut nodes = vec![(start.clone(), 0)];
+    let mut heap = BinaryHeap::from([MinState((heuristic(&start), 0), ())]);
+    let mut costs = HashMap::from([(start, 0)]);
+    let mut parents: HashMap<N, N> = HashMap::new();
+
+    while let Some(MinState((_, index), ())) = heap.pop() {
+        let (current, cost) = nodes[index].clone();
+        if cost > costs[&current] {
+            continue;
+        }
+
+        if is_goal(&current) {
+            let mut path = vec![current.clone()];
+            let mut node = &current;
+            while let Some(parent) = parents.get(node) {
+                path.push(parent.clone());
+                node = parent;
+            }
+            path.reverse();
+
+            return Some((cost, path));
+        }
+
+        for (next, step_cost) in neighbors(&current) {
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_none_or(|&known| next_cost < known) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), current.clone());
+                heap.push(MinState((next_cost + heuristic(&next), nodes.len()), ()));
+                nodes.push((next, next_cost));
+            }
+        }
+    }
+
+    None
+}
+
+///
+/// # `connected_components`
+/// Groups nodes into components of nodes reachable from one another.
+/// For grids, `neighbors` only returns the adjacent cells holding the same value.
+///
+/// ## Arguments
+/// * `nodes` - The nodes to group, each one ends up in exactly one component
+/// * `neighbors` - The nodes directly connected to a node
+///
+/// ## Returns
+/// * `Vec<Vec<N>>` - The components, in the order their first node appears in `nodes`
+pub fn connected_components<N>(
+    nodes: impl IntoIterator<Item = N>,
+    neighbors: impl Fn(&N) -> Vec<N>,
+) -> Vec<Vec<N>>
+where
+    N: Hash + Eq + Clone,
+{
+    let mut seen = HashSet::new();
+    let mut components = Vec::new();
+
+    for node in nodes {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+
+        let mut component = Vec::new();
+        let mut stack = vec![node];
+        while let Some(current) = stack.pop() {
+            for next in neighbors(&current) {
+                if seen.insert(next.clone()) {
+                    stack.push(next);
+                }
+            }
+            component.push(current);
+        }
+
+        components.push(component);
+    }
+
+    components
+}
+
+///
+/// # `topo_sort`
+/// Topological sort with Kahn's algorithm: every edge `(a, b)` puts `a` before `b`.
+/// Edges touching a node outside `nodes` are ignored, duplicated nodes are only sorted once
+/// and ties keep the order of `nodes`.
+///
+/// ## Arguments
+/// * `nodes` - The nodes to sort
+/// * `edges` - The `(before, after)` constraints
+///
+/// ## Returns
+/// * `Result<Vec<N>, Vec<N>>` - The sorted nodes, or the nodes lying on a cycle in the order of
+///   `nodes` (the ones only behind a cycle are left out)
+pub fn topo_sort<N>(nodes: &[N], edges: &[(N, N)]) -> Result<Vec<N>, Vec<N>>
+where
+    N: Hash + Eq + Clone,
+{
+    let mut seen = HashSet::new();
+    let nodes: Vec<&N> = nodes.iter().filter(|&node| seen.insert(node)).collect();
+
+    let mut in_degrees: HashMap<&N, usize> = nodes.iter().map(|&node| (node, 0)).collect();
+    let mut successors: HashMap<&N, Vec<&N>> = HashMap::new();
+
+    for (before, after) in edges {
+        if in_degrees.contains_key(before) && in_degrees.contains_key(after) {
+            *in_degrees.get_mut(after).unwrap() += 1;
+            successors.entry(before).or_default().push(after);
+        }
+    }
+
+    let mut queue: VecDeque<&N> = nodes
+        .iter()
+        .copied()
+        .filter(|node| in_degrees[node] == 0)
+        .collect();
+    let mut sorted = Vec::with_capacity(nodes.len());
+
+    while let Some(node) = queue.pop_front() {
+        sorted.push(node.clone());
+
+        for &next in successors.get(node).into_iter().flatten() {
+            let degree = in_degrees.get_mut(next).unwrap();
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    if sorted.len() == nodes.len() {
+        return Ok(sorted);
+    }
+
+    // The unsorted nodes are on a cycle or behind one, only the former can reach themselves
+    let unsorted_successors = |node: &&N| -> Vec<&N> {
+        successors
+            .get(node)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|next| in_degrees[next] > 0)
+            .collect()
+    };
+
+    Err(nodes
+        .iter()
+        .copied()
+        .filter(|node| in_degrees[node] > 0)
+        .filter(|&node| {
+            unsorted_successors(&node)
+                .into_iter()
+                .any(|next| distances_from(next, unsorted_successors).contains_key(node))
+        })
+        .cloned()
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point;
+
+    //  a - b - c - f
+    //   \     /
+    //    d - e       g
+    fn adjacency() -> HashMap<char, Vec<char>> {
+        HashMap::from([
+            ('a', vec!['b', 'd']),
+            ('b', vec!['a', 'c']),
+            ('c', vec!['b', 'e', 'f']),
+            ('d', vec!['a', 'e']),
+            ('e', vec!['d', 'c']),
+            ('f', vec!['c']),
+            ('g', vec![]),
+        ])
+    }
+
+    #[test]
+    fn test_bfs() {
+        let graph = adjacency();
+        let result = bfs('a', |n| graph[n].clone(), |&n| n == 'f').unwrap();
+
+        assert_eq!(result.goal(), &'f');
+        assert_eq!(result.distance(&'f'), Some(3));
+        assert_eq!(result.distance(&'a'), Some(0));
+        assert_eq!(result.distance(&'g'), None);
+        assert_eq!(result.path_to(&'f'), Some(vec!['a', 'b', 'c', 'f']));
+        assert_eq!(result.path_to(&'a'), Some(vec!['a']));
+    }
+
+    #[test]
+    fn test_bfs_unreachable() {
+        let graph = adjacency();
+        assert!(bfs('a', |n| graph[n].clone(), |&n| n == 'g').is_none());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // a -> b is cheap but b -> d is expensive, going through c wins
+        let graph = HashMap::from([
+            ('a', vec![('b', 1), ('c', 4)]),
+            ('b', vec![('d', 10), ('c', 2)]),
+            ('c', vec![('d', 3)]),
+            ('d', vec![]),
+            ('e', vec![('a', 1)]),
+        ]);
+
+        assert_eq!(
+            dijkstra('a', |n| graph[n].clone(), |&n| n == 'd'),
+            Some((6, vec!['a', 'b', 'c', 'd']))
+        );
+        assert_eq!(
+            dijkstra('a', |n| graph[n].clone(), |&n| n == 'a'),
+            Some((0, vec!['a']))
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_disconnected() {
+        let graph = HashMap::from([('a', vec![('b', 1)]), ('b', vec![('a', 1)]), ('c', vec![])]);
+
+        assert_eq!(dijkstra('a', |n| graph[n].clone(), |&n| n == 'c'), None);
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let maze = [
+            "S........",
+            ".###.#.#.",
+            ".#...#.#.",
+            ".#.###.#.",
+            "...#...#E",
+        ];
+        let grid: Vec<Vec<char>> = maze.iter().map(|row| row.chars().collect()).collect();
+        let (start, end) = (Point::new(0, 0), Point::new(8, 4));
+
+        let neighbors = |p: &Point<usize>| {
+            p.neighbors4()
+                .into_iter()
+                .filter(|n| {
+                    grid.get(n.y)
+                        .and_then(|row| row.get(n.x))
+                        .is_some_and(|&c| c != '#')
+                })
+                .map(|n| (n, 1))
+                .collect()
+        };
+
+        let (astar_cost, astar_path) = astar(
+            start,
+            neighbors,
+            |p| p.manhattan_distance(&end),
+            |&p| p == end,
+        )
+        .unwrap();
+        let (dijkstra_cost, dijkstra_path) = dijkstra(start, neighbors, |&p| p == end).unwrap();
+
+        assert_eq!(astar_cost, dijkstra_cost);
+        assert_eq!(astar_path.len(), dijkstra_path.len());
+        assert_eq!(astar_path.len(), astar_cost + 1);
+        assert_eq!(astar_path.first(), Some(&start));
+        assert_eq!(astar_path.last(), Some(&end));
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let garden = ["AAB", "ABB", "CCB"];
+        let grid: Vec<Vec<char>> = garden.iter().map(|row| row.chars().collect()).collect();
+        let cells = (0..3).flat_map(|y| (0..3).map(move |x| Point::new(x, y)));
+
+        let components = connected_components(cells, |p: &Point<usize>| {
+            p.neighbors4()
+                .into_iter()
+                .filter(|n| n != p && n.in_bounds(3, 3) && grid[n.y][n.x] == grid[p.y][p.x])
+                .collect()
+        });
+
+        let sizes: Vec<usize> = components.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![3, 4, 2]);
+        assert!(components[0].contains(&Point::new(0, 1)));
+        assert!(components[2].contains(&Point::new(1, 2)));
+    }
+
+    #[test]
+    fn test_min_state() {
+        let mut heap = BinaryHeap::new();
+        heap.push(MinState(5_usize, 'c'));
+        heap.push(MinState(1, 'a'));
+        heap.push(MinState(9, 'd'));
+        heap.push(MinState(3, 'b'));
+
+        let popped: Vec<char> = std::iter::from_fn(|| heap.pop().map(|MinState(_, c)| c)).collect();
+        assert_eq!(popped, vec!['a', 'b', 'c', 'd']);
+
+        assert_ne!(MinState(1, 'a'), MinState(1, 'b'));
+        assert!(MinState(1, 'a') > MinState(2, 'a'));
+    }
+
+    #[test]
+    fn test_topo_sort() {
+        let nodes = ['d', 'c', 'b', 'a'];
+        let edges = [('a', 'b'), ('b', 'c'), ('a', 'c'), ('c', 'd'), ('x', 'a')];
+
+        let sorted = topo_sort(&nodes, &edges).unwrap();
+        assert_eq!(sorted, vec!['a', 'b', 'c', 'd']);
+
+        let unconstrained = topo_sort(&['z', 'y'], &edges).unwrap();
+        assert_eq!(unconstrained, vec!['z', 'y']);
+    }
+
+    #[test]
+    fn test_topo_sort_cycle() {
+        let nodes = [1, 2, 3, 4];
+        let edges = [(1, 2), (2, 3), (3, 2), (3, 4)];
+
+        // 4 is only behind the cycle
+        assert_eq!(topo_sort(&nodes, &edges), Err(vec![2, 3]));
+
+        // Between two cycles without being on one
+        let nodes = [1, 2, 3, 4, 5, 6];
+        let edges = [
+            (1, 2),
+            (2, 1),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 4),
+            (5, 6),
+            (6, 6),
+        ];
+        assert_eq!(topo_sort(&nodes, &edges), Err(vec![1, 2, 4, 5, 6]));
+    }
+
+    #[test]
+    fn test_topo_sort_duplicated_nodes() {
+        let nodes = ['b', 'a', 'b'];
+        let edges = [('a', 'b')];
+
+        assert_eq!(topo_sort(&nodes, &edges), Ok(vec!['a', 'b']));
+    }
+
+    #[test]
+    fn test_distances_from() {
+        let graph = adjacency();
+        let distances = distances_from('a', |n| graph[n].clone());
+
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances[&'a'], 0);
+        assert_eq!(distances[&'e'], 2);
+        assert_eq!(distances[&'f'], 3);
+        assert!(!distances.contains_key(&'g'));
+    }
+}
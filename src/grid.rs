@@ -0,0 +1,386 @@
+use crate::{Direction, Point};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+///
+/// # `Grid`
+/// A 2-D grid stored flat, row by row, indexed with `Point::to_index`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum GridParseError {
+    // Line (0-based) whose length differs from the first one
+    RaggedLine(usize),
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            data: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    ///
+    /// # `from_rows`
+    /// Builds a grid from its rows.
+    ///
+    /// ## Arguments
+    /// * `rows` - The rows, all of the same length
+    ///
+    /// ## Returns
+    /// * `Result<Grid<T>, GridParseError>` - The grid, or the first row whose length differs from the first one
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridParseError> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(GridParseError::RaggedLine(y));
+        }
+
+        Ok(Grid {
+            data: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, p: Point<usize>) -> bool {
+        p.in_bounds(self.width, self.height)
+    }
+
+    pub fn get(&self, p: Point<usize>) -> Option<&T> {
+        self.in_bounds(p)
+            .then(|| &self.data[p.to_index(self.width)])
+    }
+
+    pub fn get_mut(&mut self, p: Point<usize>) -> Option<&mut T> {
+        if self.in_bounds(p) {
+            Some(&mut self.data[p.to_index(self.width)])
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// # `set`
+    /// Replaces the value of a cell.
+    ///
+    /// ## Arguments
+    /// * `p` - The cell to update
+    /// * `value` - The new value
+    ///
+    /// ## Returns
+    /// * `Option<T>` - The previous value, `None` (and nothing is stored) if `p` is out of bounds
+    pub fn set(&mut self, p: Point<usize>, value: T) -> Option<T> {
+        self.get_mut(p).map(|cell| std::mem::replace(cell, value))
+    }
+
+    // In-bounds neighbors in the order Up, Right, Down, Left
+    pub fn neighbors4(&self, p: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        Direction::cardinals()
+            .into_iter()
+            .filter_map(move |direction| direction.step(p, self.width, self.height))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` since `chunks` panics on 0, an empty grid has no data anyway
+        self.data.chunks(self.width.max(1))
+    }
+
+    pub fn col(&self, x: usize) -> impl Iterator<Item = &T> {
+        let len = if x < self.width { self.height } else { 0 };
+
+        self.data
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(len)
+    }
+
+    ///
+    /// # `map`
+    /// Builds a grid of the same size from the value of every cell.
+    ///
+    /// ## Arguments
+    /// * `f` - Computes the new value of a cell
+    ///
+    /// ## Returns
+    /// * `Grid<U>` - The mapped grid
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    ///
+    /// # `transform`
+    /// Replaces every cell in place, row by row.
+    ///
+    /// ## Arguments
+    /// * `f` - Computes the new value of a cell from its position and current value
+    pub fn transform(&mut self, mut f: impl FnMut(Point<usize>, &T) -> T) {
+        for (index, cell) in self.data.iter_mut().enumerate() {
+            *cell = f(Point::from_index(index, self.width), cell);
+        }
+    }
+
+    ///
+    /// # `scale`
+    /// Blows every cell up into a `kx` x `ky` block of cells.
+    ///
+    /// ## Arguments
+    /// * `kx` - The horizontal factor
+    /// * `ky` - The vertical factor
+    /// * `expand` - Computes a sub-cell from the original cell and the sub-cell's column and row in its block
+    ///
+    /// ## Returns
+    /// * `Grid<T>` - The `width * kx` x `height * ky` grid
+    pub fn scale(&self, kx: usize, ky: usize, expand: impl Fn(&T, usize, usize) -> T) -> Grid<T> {
+        let width = self.width * kx;
+        let height = self.height * ky;
+        let data = (0..width * height)
+            .map(|index| {
+                let Point { x, y } = Point::from_index(index, width);
+                expand(&self[Point::new(x / kx, y / ky)], x % kx, y % ky)
+            })
+            .collect();
+
+        Grid {
+            data,
+            width,
+            height,
+        }
+    }
+
+    // Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (Point::from_index(index, self.width), value))
+    }
+}
+
+///
+/// # `debug_grid`
+/// Renders a grid with its column indexes above and its row indexes on the left,
+/// both modulo 10 so that every index is a single digit. Meant for debugging prints.
+///
+/// ## Arguments
+/// * `grid` - The grid to render
+///
+/// ## Returns
+/// * `String` - The header line then one line per row, each ending with a newline
+pub fn debug_grid(grid: &[Vec<char>]) -> String {
+    let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+
+    let header: String = (0..width)
+        .map(|x| char::from_digit((x % 10) as u32, 10).unwrap())
+        .collect();
+    let mut output = format!("  {header}\n");
+
+    for (y, row) in grid.iter().enumerate() {
+        output.push_str(&format!("{} {}\n", y % 10, row.iter().collect::<String>()));
+    }
+
+    output
+}
+
+// Panics when the point is out of bounds, like slice indexing
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<usize>) -> &Self::Output {
+        self.get(p).expect("Point out of the grid")
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, p: Point<usize>) -> &mut Self::Output {
+        self.get_mut(p).expect("Point out of the grid")
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut data = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (y, line) in s.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let len = line.chars().count();
+            if y == 0 {
+                width = len;
+            } else if len != width {
+                return Err(GridParseError::RaggedLine(y));
+            }
+
+            data.extend(line.chars());
+            height += 1;
+        }
+
+        Ok(Grid {
+            data,
+            width,
+            height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#..
+.S.
+..E
+";
+
+    #[test]
+    fn test_from_str() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.get(Point::new(0, 0)), Some(&'#'));
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&'S'));
+        assert_eq!(grid.get(Point::new(2, 2)), Some(&'E'));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+
+        assert_eq!(
+            "ab\nc".parse::<Grid<char>>(),
+            Err(GridParseError::RaggedLine(1))
+        );
+    }
+
+    #[test]
+    fn test_mutation() {
+        let mut grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(grid.set(Point::new(1, 1), '.'), Some('S'));
+        assert_eq!(grid.set(Point::new(5, 5), '.'), None);
+        *grid.get_mut(Point::new(2, 0)).unwrap() = '#';
+
+        assert_eq!(grid.row(0), Some(&['#', '.', '#'][..]));
+        assert_eq!(grid.row(1), Some(&['.', '.', '.'][..]));
+        assert_eq!(grid.row(3), None);
+    }
+
+    #[test]
+    fn test_rows_and_cols() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.col(2).collect::<String>(), "..E");
+        assert_eq!(grid.col(3).count(), 0);
+        assert_eq!(
+            grid.iter().find(|&(_, &c)| c == 'E').map(|(p, _)| p),
+            Some(Point::new(2, 2))
+        );
+    }
+
+    #[test]
+    fn test_neighbors4() {
+        let grid = Grid::new(3, 2, 0);
+
+        assert_eq!(
+            grid.neighbors4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors4(Point::new(2, 1)).collect::<Vec<_>>(),
+            vec![Point::new(2, 0), Point::new(1, 1)]
+        );
+        assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 3);
+    }
+
+    #[test]
+    fn test_map() {
+        let grid: Grid<char> = "0123\n1234\n8765".parse().unwrap();
+        let heights: Grid<u8> = grid.map(|c| c.to_digit(10).unwrap() as u8);
+
+        assert_eq!((heights.width(), heights.height()), (4, 3));
+        assert_eq!(heights.get(Point::new(0, 0)), Some(&0));
+        assert_eq!(heights.get(Point::new(3, 1)), Some(&4));
+        assert_eq!(heights.row(2), Some(&[8, 7, 6, 5][..]));
+    }
+
+    #[test]
+    fn test_transform() {
+        let mut grid = Grid::new(3, 2, 1_usize);
+        grid.transform(|p, &value| value + p.x * 10 + p.y);
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.row(0), Some(&[1, 11, 21][..]));
+        assert_eq!(grid.row(1), Some(&[2, 12, 22][..]));
+    }
+
+    #[test]
+    fn test_scale() {
+        let grid = Grid::from_rows(vec![vec!['#', 'O'], vec!['.', '@']]).unwrap();
+        let doubled = grid.scale(2, 1, |&c, sub_x, _| match (c, sub_x) {
+            ('O', 0) => '[',
+            ('O', _) => ']',
+            ('@', 1) => '.',
+            (c, _) => c,
+        });
+
+        assert_eq!((doubled.width(), doubled.height()), (4, 2));
+        assert_eq!(doubled.row(0), Some(&['#', '#', '[', ']'][..]));
+        assert_eq!(doubled.row(1), Some(&['.', '.', '@', '.'][..]));
+
+        let tall = grid.scale(1, 3, |&c, _, _| c);
+        assert_eq!((tall.width(), tall.height()), (2, 6));
+        assert_eq!(tall.col(1).collect::<String>(), "OOO@@@");
+    }
+
+    #[test]
+    fn test_from_rows_and_index() {
+        let mut grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        grid[Point::new(1, 0)] = 5;
+
+        assert_eq!(grid[Point::new(1, 0)], 5);
+        assert_eq!(grid[Point::new(0, 1)], 3);
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3]]),
+            Err(GridParseError::RaggedLine(1))
+        );
+    }
+
+    #[test]
+    fn test_debug_grid() {
+        let grid = vec![vec!['.'; 12], vec!['#'; 12], vec!['.'; 12]];
+        let output = debug_grid(&grid);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "  012345678901");
+        assert_eq!(lines[1], "0 ............");
+        assert_eq!(lines[2], "1 ############");
+    }
+}
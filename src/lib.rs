@@ -1,8 +1,27 @@
+mod digits;
 mod directions;
+mod graph;
+mod grid;
+mod memo;
+mod parse;
 mod points;
+mod pose;
 
-pub use directions::Direction;
-pub use points::Point;
+pub use digits::{concat, num_digits, split_even};
+pub use directions::{parse_moves, reverse_path, Direction, DirectionError};
+pub use graph::{
+    astar, bfs, connected_components, dijkstra, distances_from, topo_sort, BfsResult, MinState,
+};
+pub use grid::{debug_grid, Grid, GridParseError};
+pub use memo::Memoizer;
+pub use parse::{
+    find_all, find_char, parse_char_grid, parse_char_grid_padded, parse_int_columns,
+    parse_int_rows, ColumnsParseError,
+};
+pub use points::{
+    bounding_box, manhattan_ball, render, Point, PointConversionError, PointParseError,
+};
+pub use pose::PoseState;
 
 ///
 /// # gcd
@@ -18,17 +37,265 @@ pub fn gcd<T>(a: T, b: T) -> T
 where
     T: std::ops::Rem<Output = T> + PartialEq + Copy + num::Zero,
 {
-    if b == T::zero() {
-        a
+    let (mut a, mut b) = (a, b);
+    while b != T::zero() {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+///
+/// # lcm
+/// Least common multiple of two numbers, divides before multiplying to avoid overflow
+///
+/// ## Arguments
+/// * `a` - The first number
+/// * `b` - The second number
+///
+/// ## Returns
+/// * `T` - The least common multiple of `a` and `b`, zero if either is zero
+pub fn lcm<T>(a: T, b: T) -> T
+where
+    T: std::ops::Rem<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + PartialEq
+        + Copy
+        + num::Zero,
+{
+    if a == T::zero() || b == T::zero() {
+        return T::zero();
+    }
+
+    a / gcd(a, b) * b
+}
+
+///
+/// # lcm_all
+/// Least common multiple of every number in a slice
+///
+/// ## Arguments
+/// * `values` - The numbers
+///
+/// ## Returns
+/// * `T` - The least common multiple of all `values`, one for an empty slice
+pub fn lcm_all<T>(values: &[T]) -> T
+where
+    T: std::ops::Rem<Output = T>
+        + std::ops::Mul<Output = T>
+        + std::ops::Div<Output = T>
+        + PartialEq
+        + Copy
+        + num::Zero
+        + num::One,
+{
+    values.iter().fold(T::one(), |acc, &value| lcm(acc, value))
+}
+
+///
+/// # `extended_gcd`
+/// Extended Euclidean algorithm
+///
+/// ## Arguments
+/// * `a` - The first number
+/// * `b` - The second number
+///
+/// ## Returns
+/// * `(i64, i64, i64)` - `(g, x, y)` where `g` is the gcd and `a * x + b * y == g`
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    // Iterative, like `gcd`, each pair holding the previous and the current value
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    (r0, x0, y0)
+}
+
+///
+/// # `mod_inverse`
+/// Modular multiplicative inverse
+///
+/// ## Arguments
+/// * `a` - The number to invert
+/// * `m` - The modulus
+///
+/// ## Returns
+/// * `Option<i64>` - `v` in `0..m` with `(a * v).rem_euclid(m) == 1`, `None` if `a` and `m` aren't
+///   coprime or `m` is 0
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m == 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+///
+/// # `variance`
+/// Population variance of a list of values.
+/// The variance is a measure of how spread out the values are.
+///
+/// # Arguments
+/// * `values` - The values
+///
+/// # Returns
+/// * `f64` - The variance of the values, `NaN` for an empty slice
+pub fn variance<I: Into<f64> + Copy>(values: &[I]) -> f64 {
+    let len = values.len() as f64;
+    let mean = values.iter().map(|&v| v.into()).sum::<f64>() / len;
+
+    values
+        .iter()
+        .map(|&v| {
+            let diff = v.into() - mean;
+
+            diff * diff
+        })
+        .sum::<f64>()
+        / len
+}
+
+///
+/// # `variance_xy`
+/// Variance of the x and y coordinates of a list of positions, in a single pass.
+///
+/// # Arguments
+/// * `positions` - A slice of tuples where each tuple represents a position (x, y)
+///
+/// # Returns
+/// * `(f64, f64)` - The variance of the x-coordinates and of the y-coordinates
+pub fn variance_xy(positions: &[(i32, i32)]) -> (f64, f64) {
+    let len = positions.len() as f64;
+    let (sum_x, sum_y, sum_x2, sum_y2) =
+        positions
+            .iter()
+            .fold((0.0, 0.0, 0.0, 0.0), |(sx, sy, sx2, sy2), &(x, y)| {
+                let (x, y) = (f64::from(x), f64::from(y));
+                (sx + x, sy + y, sx2 + x * x, sy2 + y * y)
+            });
+
+    let (mean_x, mean_y) = (sum_x / len, sum_y / len);
+
+    (
+        sum_x2 / len - mean_x * mean_x,
+        sum_y2 / len - mean_y * mean_y,
+    )
+}
+
+///
+/// # `crt`
+/// Chinese Remainder Theorem: solves `x ≡ r (mod m)` for every `(r, m)` pair.
+/// Moduli don't need to be coprime, as long as the congruences agree.
+///
+/// ## Arguments
+/// * `residues` - The `(remainder, modulus)` pairs
+///
+/// ## Returns
+/// * `Option<i64>` - The smallest non-negative solution, `None` if the congruences are incompatible
+///   or a modulus is 0
+pub fn crt(residues: &[(i64, i64)]) -> Option<i64> {
+    let (remainder, _) = residues
+        .iter()
+        .try_fold((0_i64, 1_i64), |(r1, m1), &(r2, m2)| {
+            if m2 == 0 {
+                return None;
+            }
+
+            let (g, p, _) = extended_gcd(m1, m2);
+            let diff = r2 - r1;
+            if diff % g != 0 {
+                return None;
+            }
+
+            // x = r1 + m1 * k, with m1 * k ≡ r2 - r1 (mod m2)
+            let step = m2 / g;
+            let k = (i128::from(diff / g) * i128::from(p)).rem_euclid(i128::from(step));
+            let modulus = m1 / g * m2;
+            let x = (i128::from(r1) + i128::from(m1) * k).rem_euclid(i128::from(modulus));
+
+            Some((x as i64, modulus))
+        })?;
+
+    Some(remainder)
+}
+
+///
+/// # `checked_sum`
+/// Sum of a list of values that reports overflow instead of wrapping.
+///
+/// # Arguments
+/// * `xs` - The values
+///
+/// # Returns
+/// * `Option<T>` - The sum, `None` if it overflows `T`
+pub fn checked_sum<T: num::CheckedAdd + Copy + num::Zero>(xs: &[T]) -> Option<T> {
+    xs.iter().try_fold(T::zero(), |acc, x| acc.checked_add(x))
+}
+
+///
+/// # `mean`
+/// Arithmetic mean of a list of values.
+///
+/// # Arguments
+/// * `xs` - The values
+///
+/// # Returns
+/// * `Option<f64>` - The mean, `None` for an empty slice
+pub fn mean(xs: &[f64]) -> Option<f64> {
+    (!xs.is_empty()).then(|| xs.iter().sum::<f64>() / xs.len() as f64)
+}
+
+///
+/// # `stddev`
+/// Population standard deviation of a list of values.
+///
+/// # Arguments
+/// * `xs` - The values
+///
+/// # Returns
+/// * `Option<f64>` - The standard deviation, `None` for an empty slice
+pub fn stddev(xs: &[f64]) -> Option<f64> {
+    mean(xs).map(|_| variance(xs).sqrt())
+}
+
+///
+/// # `median`
+/// Median of a list of values, sorting the slice in place.
+///
+/// # Arguments
+/// * `xs` - The values, sorted on return
+///
+/// # Returns
+/// * `Option<f64>` - The median (mean of the two middle values for an even length), `None` for an empty slice
+pub fn median(xs: &mut [f64]) -> Option<f64> {
+    if xs.is_empty() {
+        return None;
+    }
+
+    xs.sort_by(f64::total_cmp);
+    let mid = xs.len() / 2;
+
+    if xs.len().is_multiple_of(2) {
+        Some((xs[mid - 1] + xs[mid]) / 2.0)
     } else {
-        gcd(b, a % b)
+        Some(xs[mid])
     }
 }
 
 ///
 /// # `calculate_variance`
 /// Calculate the variance of the x-coordinates of a list of positions.
-/// The variance is a measure of how spread out the values are.
+/// Kept for compatibility, see `variance` and `variance_xy`.
 ///
 /// # Arguments
 /// * `positions` - A slice of tuples where each tuple represents a position (x, y)
@@ -36,19 +303,154 @@ where
 /// # Returns
 /// * `f64` - The variance of the x-coordinates
 pub fn calculate_variance(positions: &[(i32, i32)]) -> f64 {
-    // Calculate the mean of the x-coordinates
-    let mean = positions.iter().map(|&(x, _)| f64::from(x)).sum::<f64>() / positions.len() as f64;
+    variance(&positions.iter().map(|&(x, _)| x).collect::<Vec<_>>())
+}
 
-    // Calculate the variance of the x-coordinates
-    let variance = positions
-        .iter()
-        .map(|&(x, _)| {
-            let diff = f64::from(x) - mean;
+///
+/// # `input_path`
+/// Path of the puzzle input of a day, inputs aren't committed and must be placed there.
+///
+/// # Arguments
+/// * `day` - The day, from 1 to 25
+///
+/// # Returns
+/// * `PathBuf` - `data/inputs/day_XX.txt` from the crate root
+pub fn input_path(day: u8) -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("data/inputs")
+        .join(format!("day_{day:02}.txt"))
+}
 
-            diff * diff
-        })
-        .sum::<f64>()
-        / positions.len() as f64;
+///
+/// # `load_input`
+/// Read the puzzle input of a day at runtime, unlike `include_str!` a missing input is
+/// only an error once the day actually runs.
+///
+/// # Arguments
+/// * `day` - The day, from 1 to 25
+///
+/// # Returns
+/// * `String` - The content of the input
+///
+/// # Panics
+/// * If the input can't be read, with the path where it is expected
+pub fn load_input(day: u8) -> String {
+    let path = input_path(day);
+
+    std::fs::read_to_string(&path).unwrap_or_else(|error| {
+        panic!(
+            "missing input for day {day}, place it at {} ({error})",
+            path.display()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4_u64, 6), 12);
+        assert_eq!(lcm(21_u64, 6), 42);
+        assert_eq!(lcm(0_u64, 6), 0);
+        assert_eq!(lcm_all(&[2_u64, 3, 4]), 12);
+        assert_eq!(lcm_all::<u64>(&[]), 1);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, g);
+
+        let v = mod_inverse(101, 103).unwrap();
+        assert_eq!((101 * v).rem_euclid(103), 1);
+        assert_eq!(mod_inverse(4, 6), None);
+        assert_eq!(mod_inverse(3, 0), None);
+
+        // Consecutive Fibonacci numbers, the worst case for the number of steps
+        let (g, x, y) = extended_gcd(7_540_113_804_746_346_429, 4_660_046_610_375_530_309);
+        assert_eq!(g, 1);
+        assert_eq!(
+            7_540_113_804_746_346_429_i128 * i128::from(x)
+                + 4_660_046_610_375_530_309_i128 * i128::from(y),
+            1
+        );
+    }
 
-    variance
+    #[test]
+    fn test_variance() {
+        // mean 5, squared deviations 9 1 1 1 0 0 4 16 -> 32 / 8
+        let values = [2, 4, 4, 4, 5, 5, 7, 9];
+        assert_eq!(variance(&values), 4.0);
+
+        let positions: Vec<(i32, i32)> = values.iter().map(|&v| (v, 1)).collect();
+        assert_eq!(calculate_variance(&positions), 4.0);
+        assert_eq!(variance_xy(&positions), (4.0, 0.0));
+
+        let swapped: Vec<(i32, i32)> = positions.iter().map(|&(x, y)| (y, x)).collect();
+        assert_eq!(variance_xy(&swapped), (0.0, 4.0));
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut xs = [4.0, 1.0, 3.0, 2.0];
+
+        assert_eq!(mean(&xs), Some(2.5));
+        assert_eq!(stddev(&xs), Some(1.25_f64.sqrt()));
+        assert_eq!(median(&mut xs), Some(2.5));
+        assert_eq!(xs, [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(median(&mut [3.0, 1.0, 2.0]), Some(2.0));
+
+        assert_eq!(mean(&[]), None);
+        assert_eq!(stddev(&[]), None);
+        assert_eq!(median(&mut []), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some(23));
+
+        let (width, height, time) = (101, 103, 7083);
+        assert_eq!(
+            crt(&[(time % width, width), (time % height, height)]),
+            Some(time)
+        );
+
+        assert_eq!(crt(&[(1, 4), (3, 6)]), Some(9));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[]), Some(0));
+        assert_eq!(crt(&[(1, 4), (0, 0)]), None);
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12_u64, 18), 6);
+        assert_eq!(gcd(7_u64, 0), 7);
+        assert_eq!(gcd(0_u64, 7), 7);
+        assert_eq!(gcd(-4_i32, 6).abs(), 2);
+
+        // consecutive Fibonacci numbers are the worst case for Euclid
+        assert_eq!(gcd(832040_u64, 514229), 1);
+    }
+
+    #[test]
+    fn test_checked_sum() {
+        assert_eq!(checked_sum(&[1_u64, 2, 3, 4]), Some(10));
+        assert_eq!(checked_sum::<u64>(&[]), Some(0));
+        assert_eq!(checked_sum(&[200_u8, 55]), Some(255));
+        assert_eq!(checked_sum(&[200_u8, 56]), None);
+    }
+
+    #[test]
+    fn test_input_path() {
+        assert!(input_path(3).ends_with("data/inputs/day_03.txt"));
+    }
+
+    #[test]
+    #[should_panic(expected = "missing input for day 0, place it at ")]
+    fn test_load_input_missing() {
+        load_input(0);
+    }
 }
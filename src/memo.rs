@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+///
+/// # `Memoizer`
+/// Cache for recursive solvers: the compute closure gets the memoizer back so it can recurse
+/// through it, e.g. `memo.get_or_compute((stone, iters), |m| ...)`.
+#[derive(Debug, Clone)]
+pub struct Memoizer<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K, V> Default for Memoizer<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memoizer<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// # `get_or_compute`
+    /// Returns the cached value for `key`, computing and caching it with `f` on a miss.
+    ///
+    /// ## Arguments
+    /// * `key` - The key to look up
+    /// * `f` - Computes the value, receives the memoizer for recursive lookups
+    ///
+    /// ## Returns
+    /// * `V` - The value for `key`
+    pub fn get_or_compute(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(self);
+        self.cache.insert(key, value.clone());
+
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fib(n: u64, memo: &mut Memoizer<u64, u64>, calls: &mut usize) -> u64 {
+        memo.get_or_compute(n, |m| {
+            *calls += 1;
+            if n < 2 {
+                n
+            } else {
+                fib(n - 1, m, calls) + fib(n - 2, m, calls)
+            }
+        })
+    }
+
+    #[test]
+    fn test_memoized_fibonacci() {
+        let mut memo = Memoizer::new();
+        let mut calls = 0;
+
+        assert_eq!(fib(50, &mut memo, &mut calls), 12_586_269_025);
+        assert_eq!(calls, 51);
+        assert_eq!(memo.len(), 51);
+
+        assert_eq!(fib(50, &mut memo, &mut calls), 12_586_269_025);
+        assert_eq!(calls, 51);
+    }
+}
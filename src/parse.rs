@@ -0,0 +1,240 @@
+use crate::Point;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+///
+/// # `parse_int_rows`
+/// Parses lines of whitespace-separated integers, blank lines are skipped.
+///
+/// ## Arguments
+/// * `s` - The text to parse
+///
+/// ## Returns
+/// * `Result<Vec<Vec<T>>, ParseIntError>` - One row per line, or the first parsing error
+pub fn parse_int_rows<T>(s: &str) -> Result<Vec<Vec<T>>, ParseIntError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.split_whitespace().map(str::parse).collect())
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ColumnsParseError {
+    InvalidInt(ParseIntError),
+    // Row (0-based, blank lines skipped) whose length differs from the first one
+    RaggedRow {
+        row: usize,
+        len: usize,
+        width: usize,
+    },
+}
+
+impl From<ParseIntError> for ColumnsParseError {
+    fn from(error: ParseIntError) -> Self {
+        ColumnsParseError::InvalidInt(error)
+    }
+}
+
+///
+/// # `parse_int_columns`
+/// Parses lines of whitespace-separated integers and transposes them into columns.
+///
+/// ## Arguments
+/// * `s` - The text to parse
+///
+/// ## Returns
+/// * `Result<Vec<Vec<T>>, ColumnsParseError>` - One vector per column, or the first parsing
+///   error or row whose length differs from the first one
+pub fn parse_int_columns<T>(s: &str) -> Result<Vec<Vec<T>>, ColumnsParseError>
+where
+    T: FromStr<Err = ParseIntError> + Copy,
+{
+    let rows = parse_int_rows(s)?;
+    let width = rows.first().map_or(0, Vec::len);
+
+    if let Some(row) = rows.iter().position(|row| row.len() != width) {
+        return Err(ColumnsParseError::RaggedRow {
+            row,
+            len: rows[row].len(),
+            width,
+        });
+    }
+
+    Ok((0..width)
+        .map(|col| rows.iter().map(|row| row[col]).collect())
+        .collect())
+}
+
+///
+/// # `parse_char_grid`
+/// Parses a block of text into a grid of chars, trailing newlines are ignored.
+/// Lines are kept as they are, see `parse_char_grid_padded` for ragged input.
+///
+/// ## Arguments
+/// * `s` - The text to parse
+///
+/// ## Returns
+/// * `(Vec<Vec<char>>, usize, usize)` - The grid, its width (longest line) and its height
+pub fn parse_char_grid(s: &str) -> (Vec<Vec<char>>, usize, usize) {
+    let grid: Vec<Vec<char>> = s
+        .trim_end_matches(['\n', '\r'])
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+    let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+    let height = grid.len();
+
+    (grid, width, height)
+}
+
+///
+/// # `parse_char_grid_padded`
+/// Same as `parse_char_grid`, with lines shorter than the longest one padded with `fill`.
+///
+/// ## Arguments
+/// * `s` - The text to parse
+/// * `fill` - The char appended to short lines
+///
+/// ## Returns
+/// * `(Vec<Vec<char>>, usize, usize)` - The rectangular grid, its width and its height
+pub fn parse_char_grid_padded(s: &str, fill: char) -> (Vec<Vec<char>>, usize, usize) {
+    let (mut grid, width, height) = parse_char_grid(s);
+    for row in &mut grid {
+        row.resize(width, fill);
+    }
+
+    (grid, width, height)
+}
+
+///
+/// # `find_char`
+/// Finds the first cell holding `target`, scanning row by row.
+///
+/// ## Arguments
+/// * `grid` - The grid to scan
+/// * `target` - The char to look for
+///
+/// ## Returns
+/// * `Option<Point<usize>>` - The position (x is the column, y the row), `None` if absent
+pub fn find_char(grid: &[Vec<char>], target: char) -> Option<Point<usize>> {
+    grid.iter().enumerate().find_map(|(y, row)| {
+        row.iter()
+            .position(|&c| c == target)
+            .map(|x| Point::new(x, y))
+    })
+}
+
+///
+/// # `find_all`
+/// Finds every cell whose char matches `pred`, in row-major order.
+///
+/// ## Arguments
+/// * `grid` - The grid to scan
+/// * `pred` - Whether a char should be collected
+///
+/// ## Returns
+/// * `Vec<Point<usize>>` - The matching positions (x is the column, y the row)
+pub fn find_all(grid: &[Vec<char>], pred: impl Fn(char) -> bool) -> Vec<Point<usize>> {
+    grid.iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|&(_, &c)| pred(c))
+                .map(move |(x, _)| Point::new(x, y))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_int_rows() {
+        assert_eq!(
+            parse_int_rows::<i32>("1 2 3\n4 5 6").unwrap(),
+            vec![vec![1, 2, 3], vec![4, 5, 6]]
+        );
+        assert_eq!(
+            parse_int_rows::<u64>("15244   50562\n\n81245   49036\n").unwrap(),
+            vec![vec![15244, 50562], vec![81245, 49036]]
+        );
+    }
+
+    #[test]
+    fn test_parse_int_columns() {
+        assert_eq!(
+            parse_int_columns::<i32>("1 2 3\n4 5 6").unwrap(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+        assert_eq!(
+            parse_int_columns::<i32>("1 2\n3\n4 5"),
+            Err(ColumnsParseError::RaggedRow {
+                row: 1,
+                len: 1,
+                width: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_int_errors() {
+        assert!(parse_int_rows::<i32>("1 2 x\n4 5 6").is_err());
+        assert!(parse_int_columns::<i32>("1 2\n3 four").is_err());
+    }
+
+    #[test]
+    fn test_parse_char_grid() {
+        let (grid, width, height) = parse_char_grid("ab\ncd\nef\n");
+        assert_eq!((width, height), (2, 3));
+        assert_eq!(grid, vec![vec!['a', 'b'], vec!['c', 'd'], vec!['e', 'f']]);
+    }
+
+    #[test]
+    fn test_parse_char_grid_padded() {
+        let (grid, width, height) = parse_char_grid_padded("#..\n#\n.#", ' ');
+        assert_eq!((width, height), (3, 3));
+        assert_eq!(
+            grid,
+            vec![
+                vec!['#', '.', '.'],
+                vec!['#', ' ', ' '],
+                vec!['.', '#', ' '],
+            ]
+        );
+
+        let (ragged, _, _) = parse_char_grid("#..\n#");
+        assert_eq!(ragged[1].len(), 1);
+    }
+
+    #[test]
+    fn test_find_char() {
+        let (grid, _, _) = parse_char_grid("#####\n#S.E#\n#####");
+        assert_eq!(find_char(&grid, 'S'), Some(Point::new(1, 1)));
+        assert_eq!(find_char(&grid, 'E'), Some(Point::new(3, 1)));
+        assert_eq!(find_char(&grid, '^'), None);
+
+        // The first one in row-major order
+        let (grid, _, _) = parse_char_grid("..\n.a\na.");
+        assert_eq!(find_char(&grid, 'a'), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_find_all() {
+        let (grid, _, _) = parse_char_grid("a.b\n.c.\nd..");
+        assert_eq!(
+            find_all(&grid, |c| c.is_ascii_lowercase()),
+            vec![
+                Point::new(0, 0),
+                Point::new(2, 0),
+                Point::new(1, 1),
+                Point::new(0, 2),
+            ]
+        );
+        assert!(find_all(&grid, |c| c == '#').is_empty());
+    }
+}
@@ -1,29 +1,62 @@
+use crate::{gcd, Direction};
+use num::traits::{SaturatingSub, ToPrimitive};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
-use std::ops::{Add, Sub};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 use std::str::FromStr;
 
 // Define a trait that combines the necessary numeric traits
 pub trait Number:
     Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + fmt::Display
 {
+    const ZERO: Self;
 }
 
 // Implement the Number trait for the built-in numeric types
-impl Number for i8 {}
-impl Number for i16 {}
-impl Number for i32 {}
-impl Number for i64 {}
-impl Number for i128 {}
-impl Number for u8 {}
-impl Number for u16 {}
-impl Number for u32 {}
-impl Number for u64 {}
-impl Number for u128 {}
-impl Number for f32 {}
-impl Number for f64 {}
-impl Number for usize {}
+impl Number for i8 {
+    const ZERO: Self = 0;
+}
+impl Number for i16 {
+    const ZERO: Self = 0;
+}
+impl Number for i32 {
+    const ZERO: Self = 0;
+}
+impl Number for i64 {
+    const ZERO: Self = 0;
+}
+impl Number for i128 {
+    const ZERO: Self = 0;
+}
+impl Number for u8 {
+    const ZERO: Self = 0;
+}
+impl Number for u16 {
+    const ZERO: Self = 0;
+}
+impl Number for u32 {
+    const ZERO: Self = 0;
+}
+impl Number for u64 {
+    const ZERO: Self = 0;
+}
+impl Number for u128 {
+    const ZERO: Self = 0;
+}
+impl Number for f32 {
+    const ZERO: Self = 0.0;
+}
+impl Number for f64 {
+    const ZERO: Self = 0.0;
+}
+impl Number for usize {
+    const ZERO: Self = 0;
+}
 
 #[derive(Debug, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Point<T: Number> {
     pub x: T,
     pub y: T,
@@ -32,6 +65,12 @@ pub struct Point<T: Number> {
 // Most of the solutions will implement their own `FromStr` trait for the `Point` struct.
 #[allow(dead_code)]
 impl<T: Number> Point<T> {
+    /// The `(0, 0)` point, e.g. the top-left corner of a grid.
+    pub const ORIGIN: Self = Point {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+
     pub fn new(x: T, y: T) -> Self {
         Point { x, y }
     }
@@ -59,6 +98,56 @@ impl<T: Number> Sub for Point<T> {
     }
 }
 
+impl<T: Number> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Number> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+// Moves the point one step in the direction, x being the column and y the row.
+impl AddAssign<Direction> for Point<i32> {
+    fn add_assign(&mut self, d: Direction) {
+        self.x += d.col_delta() as i32;
+        self.y += d.row_delta() as i32;
+    }
+}
+
+// Same as for `(usize, usize)`, wrapping_add avoids panics when going past the origin.
+impl AddAssign<Direction> for Point<usize> {
+    fn add_assign(&mut self, d: Direction) {
+        self.x = self.x.wrapping_add(d.col_delta() as usize);
+        self.y = self.y.wrapping_add(d.row_delta() as usize);
+    }
+}
+
+impl<T: Number + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self::Output {
+        Point {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Point {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
 impl<T: Number> fmt::Display for Point<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "({}, {})", self.x, self.y)
@@ -67,7 +156,9 @@ impl<T: Number> fmt::Display for Point<T> {
 
 #[allow(dead_code)]
 impl<T: Number> Point<T> {
-    pub fn manhattan_distance(&self, other: &Self) -> T {
+    /// Absolute differences of the coordinates, computed without going below zero
+    /// so that it also works for unsigned types.
+    fn abs_deltas(&self, other: &Self) -> (T, T) {
         let dx = if self.x > other.x {
             self.x - other.x
         } else {
@@ -78,8 +169,229 @@ impl<T: Number> Point<T> {
         } else {
             other.y - self.y
         };
+        (dx, dy)
+    }
+
+    ///
+    /// # `in_bounds`
+    /// Checks if the point is inside a `width` × `height` grid anchored at the origin.
+    ///
+    /// ## Arguments
+    /// * `width` - The width of the grid
+    /// * `height` - The height of the grid
+    ///
+    /// ## Returns
+    /// * `bool` - true when `0 <= x < width` and `0 <= y < height`
+    pub fn in_bounds(&self, width: T, height: T) -> bool {
+        self.in_bounds_point(Self::ORIGIN, Point::new(width, height))
+    }
+
+    ///
+    /// # `in_bounds_point`
+    /// Checks if the point is inside the box going from `min` (inclusive) to `max` (exclusive).
+    ///
+    /// ## Arguments
+    /// * `min` - The top-left corner of the box, inclusive
+    /// * `max` - The bottom-right corner of the box, exclusive
+    ///
+    /// ## Returns
+    /// * `bool` - true when `min.x <= x < max.x` and `min.y <= y < max.y`
+    pub fn in_bounds_point(&self, min: Self, max: Self) -> bool {
+        min.x <= self.x && self.x < max.x && min.y <= self.y && self.y < max.y
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        let (dx, dy) = self.abs_deltas(other);
         dx + dy
     }
+
+    ///
+    /// # `chebyshev_distance`
+    /// Distance when diagonal moves cost the same as straight ones (8-directional movement).
+    ///
+    /// ## Arguments
+    /// * `other` - The other point
+    ///
+    /// ## Returns
+    /// * `T` - The largest of the absolute coordinate differences
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        let (dx, dy) = self.abs_deltas(other);
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+}
+
+impl<T: Number + ToPrimitive> Point<T> {
+    ///
+    /// # `euclidean_distance`
+    /// Straight-line distance between two points.
+    ///
+    /// ## Arguments
+    /// * `other` - The other point
+    ///
+    /// ## Returns
+    /// * `f64` - The euclidean distance
+    pub fn euclidean_distance(&self, other: &Self) -> f64 {
+        let (dx, dy) = self.abs_deltas(other);
+        let dx = dx.to_f64().unwrap();
+        let dy = dy.to_f64().unwrap();
+
+        dx.hypot(dy)
+    }
+}
+
+impl<T: Number + Neg<Output = T>> Point<T> {
+    ///
+    /// # `rotate_cw`
+    /// Rotates the point 90° clockwise around the origin, in screen coordinates
+    /// (x grows to the right, y grows downward): `(x, y)` becomes `(-y, x)`.
+    /// For example Right `(1, 0)` becomes Down `(0, 1)`.
+    ///
+    /// ## Returns
+    /// * `Point<T>` - The rotated point
+    pub fn rotate_cw(&self) -> Self {
+        Point {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    ///
+    /// # `rotate_ccw`
+    /// Rotates the point 90° counterclockwise around the origin, in screen coordinates
+    /// (x grows to the right, y grows downward): `(x, y)` becomes `(y, -x)`.
+    /// For example Right `(1, 0)` becomes Up `(0, -1)`.
+    ///
+    /// ## Returns
+    /// * `Point<T>` - The rotated point
+    pub fn rotate_ccw(&self) -> Self {
+        Point {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
+
+// In grids, `x` is always the column and `y` the row, so a cell is `grid[y][x]`.
+impl Point<usize> {
+    ///
+    /// # `to_index`
+    /// Converts the point to its index in a flat, row-major grid (`y * width + x`).
+    ///
+    /// ## Arguments
+    /// * `width` - The width of the grid (number of columns)
+    ///
+    /// ## Returns
+    /// * `usize` - The index of the cell in the flat grid
+    pub fn to_index(&self, width: usize) -> usize {
+        self.y * width + self.x
+    }
+
+    ///
+    /// # `from_index`
+    /// Converts an index in a flat, row-major grid back to a point.
+    ///
+    /// ## Arguments
+    /// * `index` - The index of the cell in the flat grid
+    /// * `width` - The width of the grid (number of columns)
+    ///
+    /// ## Returns
+    /// * `Point<usize>` - The point with `x = index % width` and `y = index / width`
+    pub fn from_index(index: usize, width: usize) -> Self {
+        Point {
+            x: index % width,
+            y: index / width,
+        }
+    }
+
+    ///
+    /// # `checked_step`
+    /// Moves the point one step in the given direction (any of the 8).
+    ///
+    /// ## Arguments
+    /// * `dir` - The direction to move in
+    ///
+    /// ## Returns
+    /// * `Option<Point<usize>>` - The new point, or `None` if a coordinate would go below zero
+    pub fn checked_step(&self, dir: Direction) -> Option<Point<usize>> {
+        Some(Point {
+            x: self.x.checked_add_signed(dir.col_delta())?,
+            y: self.y.checked_add_signed(dir.row_delta())?,
+        })
+    }
+}
+
+impl Point<i32> {
+    ///
+    /// # `line_to`
+    /// Walks the straight line from `self` to `other`, both inclusive, stepping by the
+    /// smallest integer step along the line (the delta divided by the gcd of its components).
+    ///
+    /// ## Arguments
+    /// * `other` - The end of the line
+    ///
+    /// ## Returns
+    /// * `impl Iterator<Item = Point<i32>>` - Every lattice point on the line, or only
+    ///   `self` if both points are equal
+    pub fn line_to(&self, other: &Self) -> impl Iterator<Item = Point<i32>> {
+        let start = *self;
+        let delta = *other - start;
+        let steps = gcd(delta.x.abs(), delta.y.abs());
+        let step = Point::new(delta.x / steps.max(1), delta.y / steps.max(1));
+
+        (0..=steps).map(move |i| start + step * i)
+    }
+}
+
+impl<T: Number + From<u8> + SaturatingSub> Point<T> {
+    ///
+    /// # `neighbors4`
+    /// The four cardinal neighbors of the point, with y growing downward.
+    /// Decrements saturate, so for unsigned coordinates a neighbor past the origin
+    /// is clamped back onto the point itself.
+    ///
+    /// ## Returns
+    /// * `[Point<T>; 4]` - The neighbors in the order Up, Right, Down, Left
+    pub fn neighbors4(&self) -> [Point<T>; 4] {
+        let one = T::from(1);
+
+        [
+            Point::new(self.x, self.y.saturating_sub(&one)),
+            Point::new(self.x + one, self.y),
+            Point::new(self.x, self.y + one),
+            Point::new(self.x.saturating_sub(&one), self.y),
+        ]
+    }
+
+    ///
+    /// # `neighbors8`
+    /// The eight neighbors (cardinal and diagonal) of the point, with y growing downward.
+    /// Decrements saturate the same way as in `neighbors4`.
+    ///
+    /// ## Returns
+    /// * `[Point<T>; 8]` - The neighbors clockwise from Up:
+    ///   Up, UpRight, Right, DownRight, Down, DownLeft, Left, UpLeft
+    pub fn neighbors8(&self) -> [Point<T>; 8] {
+        let one = T::from(1);
+        let up = self.y.saturating_sub(&one);
+        let down = self.y + one;
+        let left = self.x.saturating_sub(&one);
+        let right = self.x + one;
+
+        [
+            Point::new(self.x, up),
+            Point::new(right, up),
+            Point::new(right, self.y),
+            Point::new(right, down),
+            Point::new(self.x, down),
+            Point::new(left, down),
+            Point::new(left, self.y),
+            Point::new(left, up),
+        ]
+    }
 }
 
 impl<T: Number> From<(T, T)> for Point<T> {
@@ -106,26 +418,58 @@ impl<T: Number> From<Point<T>> for (T, T) {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub enum PointConversionError {
+    Negative,
+    Overflow,
+}
+
+impl TryFrom<Point<i32>> for Point<usize> {
+    type Error = PointConversionError;
+
+    fn try_from(point: Point<i32>) -> Result<Self, Self::Error> {
+        let x = usize::try_from(point.x).map_err(|_| PointConversionError::Negative)?;
+        let y = usize::try_from(point.y).map_err(|_| PointConversionError::Negative)?;
+
+        Ok(Point { x, y })
+    }
+}
+
+impl TryFrom<Point<usize>> for Point<i32> {
+    type Error = PointConversionError;
+
+    fn try_from(point: Point<usize>) -> Result<Self, Self::Error> {
+        let x = i32::try_from(point.x).map_err(|_| PointConversionError::Overflow)?;
+        let y = i32::try_from(point.y).map_err(|_| PointConversionError::Overflow)?;
+
+        Ok(Point { x, y })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PointParseError {
+    MissingComma,
+    InvalidComponent,
+}
+
+// Parses `x,y` coordinates, whitespace around the components is allowed ("3,4" or "3, 4").
 impl<T> FromStr for Point<T>
 where
     T: Number + FromStr,
-    T::Err: std::fmt::Debug,
 {
-    type Err = String;
+    type Err = PointParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split_whitespace().collect();
+        let (x, y) = s.split_once(',').ok_or(PointParseError::MissingComma)?;
 
-        if parts.len() < 2 {
-            return Err("Invalid point format".to_string());
-        }
-
-        let x = parts[0]
+        let x = x
+            .trim()
             .parse()
-            .map_err(|e| format!("Failed to parse x coordinate: {:?}", e))?;
-        let y = parts[1]
+            .map_err(|_| PointParseError::InvalidComponent)?;
+        let y = y
+            .trim()
             .parse()
-            .map_err(|e| format!("Failed to parse y coordinate: {:?}", e))?;
+            .map_err(|_| PointParseError::InvalidComponent)?;
 
         Ok(Point { x, y })
     }
@@ -139,6 +483,91 @@ impl<T: Number> PartialEq for Point<T> {
 
 impl<T: Number> Eq for Point<T> {}
 
+// Points are ordered like grid cells, row-major: by `y` first, then by `x`.
+impl<T: Number> PartialOrd for Point<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.y.partial_cmp(&other.y)? {
+            Ordering::Equal => self.x.partial_cmp(&other.x),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl<T: Number + Ord> Ord for Point<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.y.cmp(&other.y).then_with(|| self.x.cmp(&other.x))
+    }
+}
+
+///
+/// # `bounding_box`
+/// Computes the smallest box containing all the points.
+///
+/// ## Arguments
+/// * `points` - The points to enclose
+///
+/// ## Returns
+/// * `Option<(Point<i32>, Point<i32>)>` - The `(min, max)` corners (both inclusive),
+///   or `None` if there are no points
+pub fn bounding_box(points: &[Point<i32>]) -> Option<(Point<i32>, Point<i32>)> {
+    let first = *points.first()?;
+
+    Some(points.iter().fold((first, first), |(min, max), point| {
+        (
+            Point::new(min.x.min(point.x), min.y.min(point.y)),
+            Point::new(max.x.max(point.x), max.y.max(point.y)),
+        )
+    }))
+}
+
+///
+/// # `manhattan_ball`
+/// Every point within a manhattan distance of `center`, row by row.
+///
+/// ## Arguments
+/// * `center` - The center of the ball
+/// * `radius` - The maximum manhattan distance, inclusive
+///
+/// ## Returns
+/// * `impl Iterator<Item = (Point<i32>, i32)>` - Each point with its distance to `center`
+pub fn manhattan_ball(center: Point<i32>, radius: i32) -> impl Iterator<Item = (Point<i32>, i32)> {
+    (-radius..=radius).flat_map(move |dy| {
+        let span = radius - dy.abs();
+
+        (-span..=span).map(move |dx| {
+            (
+                Point::new(center.x + dx, center.y + dy),
+                dx.abs() + dy.abs(),
+            )
+        })
+    })
+}
+
+///
+/// # `render`
+/// Renders points into a `width` × `height` grid for ASCII visualization.
+/// Points outside of the grid are ignored.
+///
+/// ## Arguments
+/// * `points` - The points to draw
+/// * `width` - The width of the grid
+/// * `height` - The height of the grid
+/// * `marker` - The character drawn at each point, empty cells being '.'
+///
+/// ## Returns
+/// * `String` - The grid, each row ending with a newline
+pub fn render(points: &[Point<usize>], width: usize, height: usize, marker: char) -> String {
+    let mut grid = vec![vec!['.'; width]; height];
+
+    for point in points.iter().filter(|p| p.in_bounds(width, height)) {
+        grid[point.y][point.x] = marker;
+    }
+
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,6 +619,68 @@ mod tests {
         let p5 = Point::new(3.5f64, 4.5f64);
         let p6 = Point::new(1.5f64, 2.5f64);
         assert_eq!(p5 - p6, Point::new(2.0f64, 2.0f64));
+
+        assert_eq!(Point::new(3, 4) - Point::new(1, 1), Point::new(2, 3));
+    }
+
+    #[test]
+    fn test_point_assign_ops() {
+        let mut p = Point::new(0, 0);
+        let step = Point::new(2, -1);
+        for _ in 0..3 {
+            p += step;
+        }
+        assert_eq!(p, Point::new(6, -3));
+
+        p -= step;
+        assert_eq!(p, Point::new(4, -2));
+    }
+
+    #[test]
+    fn test_point_add_assign_direction() {
+        let mut p = Point::new(0, 0);
+        p += Direction::DownRight;
+        assert_eq!(p, Point::new(1, 1));
+
+        p += Direction::Up;
+        p += Direction::UpLeft;
+        assert_eq!(p, Point::new(0, -1));
+
+        for direction in [
+            Direction::Down,
+            Direction::Left,
+            Direction::Right,
+            Direction::DownLeft,
+            Direction::UpRight,
+        ] {
+            p += direction;
+        }
+        assert_eq!(p, Point::new(0, 0));
+
+        let mut p = Point::new(1usize, 1usize);
+        p += Direction::DownRight;
+        assert_eq!(p, Point::new(2, 2));
+        p += Direction::UpLeft;
+        assert_eq!(p, Point::new(1, 1));
+    }
+
+    #[test]
+    fn test_point_mul() {
+        assert_eq!(Point::new(2, 3) * 2, Point::new(4, 6));
+
+        // Test with negative numbers
+        assert_eq!(Point::new(-2i32, 3i32) * -3, Point::new(6, -9));
+
+        // Test with floating point numbers
+        assert_eq!(Point::new(1.5f64, 2.5f64) * 2.0, Point::new(3.0f64, 5.0f64));
+    }
+
+    #[test]
+    fn test_point_neg() {
+        assert_eq!(-Point::new(1, -2), Point::new(-1, 2));
+
+        let delta = Point::new(3, 4) - Point::new(1, 1);
+        assert_eq!(Point::new(1, 1) + delta * 3 + -delta, Point::new(5, 7));
     }
 
     #[test]
@@ -199,6 +690,17 @@ mod tests {
 
         let p_float = Point::new(1.5f64, 2.5f64);
         assert_eq!(format!("{}", p_float), "(1.5, 2.5)");
+
+        let p_negative = Point::new(-3, 0);
+        assert_eq!(p_negative.to_string(), "(-3, 0)");
+    }
+
+    #[test]
+    fn test_point_origin() {
+        assert_eq!(Point::<i32>::ORIGIN, Point::new(0, 0));
+        assert_eq!(Point::<usize>::ORIGIN, Point::new(0, 0));
+        assert_eq!(Point::<f64>::ORIGIN, Point::new(0.0, 0.0));
+        assert_eq!(Point::<i32>::ORIGIN.to_string(), "(0, 0)");
     }
 
     #[test]
@@ -223,6 +725,217 @@ mod tests {
         assert_eq!(p7.manhattan_distance(&p8), 0);
     }
 
+    #[test]
+    fn test_in_bounds() {
+        assert!(Point::new(0, 0).in_bounds(3, 2));
+        assert!(Point::new(2, 1).in_bounds(3, 2));
+
+        // Boundaries are exclusive
+        assert!(!Point::new(3, 1).in_bounds(3, 2));
+        assert!(!Point::new(2, 2).in_bounds(3, 2));
+
+        // Negative coordinates are rejected
+        assert!(!Point::new(-1, 0).in_bounds(3, 2));
+        assert!(!Point::new(0, -1).in_bounds(3, 2));
+
+        // Unsigned coordinates
+        assert!(Point::new(4usize, 0usize).in_bounds(5, 1));
+        assert!(!Point::new(5usize, 0usize).in_bounds(5, 1));
+    }
+
+    #[test]
+    fn test_in_bounds_point() {
+        let min = Point::new(-2, -2);
+        let max = Point::new(2, 2);
+
+        assert!(Point::new(-2, -2).in_bounds_point(min, max));
+        assert!(Point::new(1, 1).in_bounds_point(min, max));
+        assert!(!Point::new(2, 1).in_bounds_point(min, max));
+        assert!(!Point::new(-3, 0).in_bounds_point(min, max));
+    }
+
+    #[test]
+    fn test_index_conversions() {
+        let width = 7;
+        let height = 3;
+
+        assert_eq!(Point::new(0, 0).to_index(width), 0);
+        assert_eq!(Point::from_index(0, width), Point::new(0, 0));
+
+        let last = Point::new(width - 1, height - 1);
+        assert_eq!(last.to_index(width), width * height - 1);
+        assert_eq!(Point::from_index(width * height - 1, width), last);
+
+        // End of the first row, then start of the second one
+        assert_eq!(Point::from_index(6, width), Point::new(6, 0));
+        assert_eq!(Point::from_index(7, width), Point::new(0, 1));
+
+        for index in 0..width * height {
+            assert_eq!(Point::from_index(index, width).to_index(width), index);
+        }
+    }
+
+    #[test]
+    fn test_checked_step() {
+        let origin = Point::new(0usize, 0usize);
+        assert_eq!(origin.checked_step(Direction::Up), None);
+        assert_eq!(origin.checked_step(Direction::Left), None);
+        assert_eq!(origin.checked_step(Direction::UpRight), None);
+        assert_eq!(origin.checked_step(Direction::DownLeft), None);
+        assert_eq!(
+            origin.checked_step(Direction::DownRight),
+            Some(Point::new(1, 1))
+        );
+
+        let p = Point::new(2usize, 3usize);
+        assert_eq!(p.checked_step(Direction::Up), Some(Point::new(2, 2)));
+        assert_eq!(p.checked_step(Direction::Right), Some(Point::new(3, 3)));
+        assert_eq!(p.checked_step(Direction::UpLeft), Some(Point::new(1, 2)));
+    }
+
+    #[test]
+    fn test_line_to() {
+        let diagonal: Vec<_> = Point::new(0, 0).line_to(&Point::new(4, 4)).collect();
+        assert_eq!(
+            diagonal,
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 1),
+                Point::new(2, 2),
+                Point::new(3, 3),
+                Point::new(4, 4),
+            ]
+        );
+
+        let steep: Vec<_> = Point::new(0, 0).line_to(&Point::new(2, 4)).collect();
+        assert_eq!(
+            steep,
+            vec![Point::new(0, 0), Point::new(1, 2), Point::new(2, 4)]
+        );
+
+        let horizontal: Vec<_> = Point::new(3, 1).line_to(&Point::new(0, 1)).collect();
+        assert_eq!(
+            horizontal,
+            vec![
+                Point::new(3, 1),
+                Point::new(2, 1),
+                Point::new(1, 1),
+                Point::new(0, 1),
+            ]
+        );
+
+        let vertical: Vec<_> = Point::new(0, -1).line_to(&Point::new(0, 1)).collect();
+        assert_eq!(
+            vertical,
+            vec![Point::new(0, -1), Point::new(0, 0), Point::new(0, 1)]
+        );
+
+        let single: Vec<_> = Point::new(5, 5).line_to(&Point::new(5, 5)).collect();
+        assert_eq!(single, vec![Point::new(5, 5)]);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let start = Point::new(1, 0);
+
+        assert_eq!(start.rotate_cw(), Point::new(0, 1));
+        assert_eq!(start.rotate_ccw(), Point::new(0, -1));
+
+        // Four clockwise rotations return to the start
+        let mut p = start;
+        for _ in 0..4 {
+            p = p.rotate_cw();
+        }
+        assert_eq!(p, start);
+
+        // Clockwise then counterclockwise is the identity
+        let p = Point::new(-3, 5);
+        assert_eq!(p.rotate_cw().rotate_ccw(), p);
+        assert_eq!(p.rotate_ccw().rotate_cw(), p);
+    }
+
+    #[test]
+    fn test_neighbors4() {
+        let p = Point::new(2, 2);
+        assert_eq!(
+            p.neighbors4(),
+            [
+                Point::new(2, 1),
+                Point::new(3, 2),
+                Point::new(2, 3),
+                Point::new(1, 2),
+            ]
+        );
+
+        // Signed coordinates can go below the origin
+        let origin = Point::new(0i32, 0i32);
+        assert_eq!(origin.neighbors4()[0], Point::new(0, -1));
+        assert_eq!(origin.neighbors4()[3], Point::new(-1, 0));
+
+        // Unsigned coordinates saturate at the origin
+        let origin = Point::new(0usize, 0usize);
+        assert_eq!(
+            origin.neighbors4(),
+            [
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        let p = Point::new(1, 1);
+        assert_eq!(
+            p.neighbors8(),
+            [
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(2, 2),
+                Point::new(1, 2),
+                Point::new(0, 2),
+                Point::new(0, 1),
+                Point::new(0, 0),
+            ]
+        );
+
+        let origin = Point::new(0usize, 0usize);
+        assert_eq!(origin.neighbors8()[7], Point::new(0, 0));
+    }
+
+    #[test]
+    fn test_euclidean_distance() {
+        let p1 = Point::new(0, 0);
+        let p2 = Point::new(3, 4);
+        assert_eq!(p1.euclidean_distance(&p2), 5.0);
+        assert_eq!(p2.euclidean_distance(&p1), 5.0);
+
+        // Test with unsigned coordinates
+        let p3 = Point::new(0usize, 0usize);
+        let p4 = Point::new(3usize, 4usize);
+        assert_eq!(p3.euclidean_distance(&p4), 5.0);
+        assert_eq!(p4.euclidean_distance(&p3), 5.0);
+    }
+
+    #[test]
+    fn test_chebyshev_distance() {
+        let p1 = Point::new(0, 0);
+        let p2 = Point::new(3, 4);
+        assert_eq!(p1.chebyshev_distance(&p2), 4);
+
+        // Test with negative numbers
+        let p3 = Point::new(-5, 1);
+        assert_eq!(p3.chebyshev_distance(&p2), 8);
+
+        // Test with unsigned coordinates
+        let p4 = Point::new(7usize, 2usize);
+        let p5 = Point::new(1usize, 4usize);
+        assert_eq!(p4.chebyshev_distance(&p5), 6);
+    }
+
     #[test]
     fn test_from_tuple() {
         // Test From<(T, T)>
@@ -241,6 +954,56 @@ mod tests {
         assert_eq!(point, Point::new(1.5f64, 2.5f64));
     }
 
+    #[test]
+    fn test_point_from_str() {
+        assert_eq!("3,4".parse::<Point<i32>>(), Ok(Point::new(3, 4)));
+        assert_eq!("3, 4".parse::<Point<i32>>(), Ok(Point::new(3, 4)));
+        assert_eq!(" -3 , 4 ".parse::<Point<i32>>(), Ok(Point::new(-3, 4)));
+        assert_eq!("12,0".parse::<Point<usize>>(), Ok(Point::new(12, 0)));
+    }
+
+    #[test]
+    fn test_point_from_str_errors() {
+        assert_eq!(
+            "3 4".parse::<Point<i32>>(),
+            Err(PointParseError::MissingComma)
+        );
+        assert_eq!(
+            "3,four".parse::<Point<i32>>(),
+            Err(PointParseError::InvalidComponent)
+        );
+        assert_eq!(
+            "-3,4".parse::<Point<usize>>(),
+            Err(PointParseError::InvalidComponent)
+        );
+    }
+
+    #[test]
+    fn test_point_conversions() {
+        assert_eq!(
+            Point::<usize>::try_from(Point::new(-1, 2)),
+            Err(PointConversionError::Negative)
+        );
+        assert_eq!(
+            Point::<usize>::try_from(Point::new(2, -1)),
+            Err(PointConversionError::Negative)
+        );
+        assert_eq!(
+            Point::<usize>::try_from(Point::new(3, 4)),
+            Ok(Point::new(3usize, 4usize))
+        );
+
+        // Round-trip usize -> i32 -> usize
+        let p = Point::new(7usize, 11usize);
+        let signed = Point::<i32>::try_from(p).unwrap();
+        assert_eq!(signed, Point::new(7, 11));
+        assert_eq!(Point::<usize>::try_from(signed), Ok(p));
+        assert_eq!(
+            Point::<i32>::try_from(Point::new(usize::MAX, 0)),
+            Err(PointConversionError::Overflow)
+        );
+    }
+
     #[test]
     fn test_point_equality() {
         let p1 = Point::new(1, 2);
@@ -256,6 +1019,99 @@ mod tests {
         assert_eq!(p4, p5);
     }
 
+    #[test]
+    fn test_point_ord() {
+        use std::collections::BTreeSet;
+
+        let set: BTreeSet<Point<i32>> = [
+            Point::new(2, 1),
+            Point::new(0, 2),
+            Point::new(1, 0),
+            Point::new(0, 1),
+            Point::new(-1, 2),
+        ]
+        .into_iter()
+        .collect();
+
+        // Row-major order: y first, then x
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            vec![
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(2, 1),
+                Point::new(-1, 2),
+                Point::new(0, 2),
+            ]
+        );
+
+        // Ordering is consistent with equality
+        let p1 = Point::new(3, 4);
+        let p2 = Point::new(3, 4);
+        assert_eq!(p1.cmp(&p2), Ordering::Equal);
+        assert_eq!(p1 == p2, p1.cmp(&p2) == Ordering::Equal);
+        assert_ne!(Point::new(4, 3).cmp(&p1), Ordering::Equal);
+
+        // Floating point points are only partially ordered
+        assert!(Point::new(1.0f64, 0.0f64) < Point::new(0.0f64, 1.0f64));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use crate::Direction;
+
+        let points = vec![Point::new(1, 2), Point::new(-3, 4)];
+        let json = serde_json::to_string(&points).unwrap();
+        assert_eq!(json, r#"[{"x":1,"y":2},{"x":-3,"y":4}]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<Point<i32>>>(&json).unwrap(),
+            points
+        );
+
+        let json = serde_json::to_string(&Direction::UpRight).unwrap();
+        assert_eq!(json, r#""upright""#);
+        assert_eq!(
+            serde_json::from_str::<Direction>(&json).unwrap(),
+            Direction::UpRight
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [
+            Point::new(3, -1),
+            Point::new(-2, 4),
+            Point::new(0, 0),
+            Point::new(5, 2),
+        ];
+        assert_eq!(
+            bounding_box(&points),
+            Some((Point::new(-2, -1), Point::new(5, 4)))
+        );
+
+        let single = [Point::new(7, 8)];
+        assert_eq!(
+            bounding_box(&single),
+            Some((Point::new(7, 8), Point::new(7, 8)))
+        );
+
+        assert_eq!(bounding_box(&[]), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let points = [
+            Point::new(0, 0),
+            Point::new(4, 1),
+            Point::new(2, 2),
+            Point::new(5, 0), // Out of range, ignored
+            Point::new(0, 3), // Out of range, ignored
+        ];
+
+        assert_eq!(render(&points, 5, 3, '#'), "#....\n....#\n..#..\n");
+    }
+
     #[test]
     fn test_point_copy_clone() {
         let p1 = Point::new(1, 2);
@@ -277,4 +1133,28 @@ mod tests {
 
         assert_eq!(set.len(), 2); // Should only contain unique points
     }
+
+    #[test]
+    fn test_manhattan_ball() {
+        let center = Point::new(0, 0);
+        let ball: Vec<_> = manhattan_ball(center, 2).collect();
+
+        assert_eq!(ball.len(), 13);
+        assert_eq!(ball.first(), Some(&(Point::new(0, -2), 2)));
+        for &(point, distance) in &ball {
+            assert_eq!(distance, point.manhattan_distance(&center));
+            assert!(distance <= 2);
+        }
+
+        let shifted: Vec<_> = manhattan_ball(Point::new(5, 5), 1).collect();
+        assert_eq!(shifted.len(), 5);
+        assert!(shifted.contains(&(Point::new(5, 5), 0)));
+        assert!(shifted.contains(&(Point::new(6, 5), 1)));
+
+        assert_eq!(
+            manhattan_ball(center, 0).collect::<Vec<_>>(),
+            vec![(center, 0)]
+        );
+        assert_eq!(manhattan_ball(center, 20).count(), 2 * 20 * 21 + 1);
+    }
 }
@@ -0,0 +1,42 @@
+use crate::{Direction, Point};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+///
+/// # `PoseState`
+/// Where a walker stands on the grid and which way it is facing.
+/// Used as the key of visited sets when revisiting a cell facing another way is a new state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PoseState {
+    pub position: Point<usize>,
+    pub facing: Direction,
+}
+
+impl PoseState {
+    pub fn new(position: Point<usize>, facing: Direction) -> Self {
+        Self { position, facing }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_pose_state_hash() {
+        let mut seen = HashSet::new();
+
+        assert!(seen.insert(PoseState::new(Point::new(1, 2), Direction::Up)));
+        assert!(!seen.insert(PoseState::new(Point::new(1, 2), Direction::Up)));
+        assert!(seen.insert(PoseState::new(Point::new(1, 2), Direction::Right)));
+        assert!(seen.insert(PoseState::new(Point::new(2, 1), Direction::Up)));
+
+        assert_eq!(seen.len(), 3);
+        assert!(seen.contains(&PoseState {
+            position: Point::new(1, 2),
+            facing: Direction::Right,
+        }));
+    }
+}
@@ -1,15 +1,129 @@
 // Imports  ==============================================================================  Imports
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use regex::Regex;
-use std::{path::Path, process::Command, str::FromStr};
+use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+    process::Command,
+    str::FromStr,
+};
 
 // Variables  =========================================================================== Variables
+/// Comments around the generated table, everything outside of them is left untouched
+const TABLE_START: &str = "<!-- aoc:table:start -->";
+const TABLE_END: &str = "<!-- aoc:table:end -->";
+
+/// README used when there is none yet, the table goes between the markers
+const DEFAULT_README: &str = "# Advent of Code 2024
+```
+        .
+\\_____)\\_____
+/--v____ __`< My Rust solutions to the Advent of Code 2024 challenges
+    )/
+    '
+```
+
+## Overview
+This repository contains my solutions to the [Advent of Code 2024](https://adventofcode.com/2024) challenges, implemented in Rust.
+
+## Project Structure
+- `src/bin/`: Contains the daily challenge solutions
+- `src/lib.rs`: Common utilities and helper functions
+- `src/points.rs`: Point-related utilities for geometric calculations
+- `data/inputs/`: Input files for each day's challenge (not included in repository)
+
+## Solutions
+<!-- aoc:table:start -->
+<!-- aoc:table:end -->
+
+## Running the Solutions
+
+To run a specific day's solution:
+```bash
+cargo run --release --bin day_01
+```
+
+To run a specific day's solution tests:
+```bash
+cargo test --bin day_01
+```
+
+To run all tests:
+```bash
+cargo test
+```
+
+
+## License
+This project is open source and available under the MIT License.";
+
+/// Answers printed by each day the last time it was timed, to notice a changed answer
+const ANSWERS_PATH: &str = "data/answers.toml";
+
+/// A row of the solutions table, anything after the part 2 cell (the status) is ignored
+const DAY_ROW_PATTERN: &str = r"\| \[Day (?P<day_number>\d+)\]\(src/bin/day_(?:\d+)\.rs\) \| (?P<part_1>.*?) \| (?P<part_2>.*?)? \|";
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
     /// Update all days instead of only les
     #[arg(short, long)]
     all: bool,
+
+    /// Time only this day, whether it is staged or not
+    #[arg(short, long, conflicts_with = "all")]
+    day: Option<u8>,
+
+    /// Number of runs per day, the median time of each part is kept
+    #[arg(short, long, default_value_t = 3)]
+    runs: usize,
+
+    /// Number of runs per day before the measured ones, their times are discarded
+    #[arg(short, long, default_value_t = 1)]
+    warmup: usize,
+
+    /// Order of the rows of the table
+    #[arg(short, long, value_enum, default_value_t = SortOrder::Day)]
+    sort: SortOrder,
+
+    /// Print the changes to the README instead of writing them
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Root of the Advent of Code repository, found from this tool's location by default
+    root: Option<PathBuf>,
+}
+
+/// Order of the rows of the solutions table
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum SortOrder {
+    /// By day number
+    Day,
+    /// By total time of both parts, fastest first, days missing a time last
+    Speed,
+}
+
+/// Which days get timed
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    /// Staged days missing a time (the default)
+    Staged,
+    /// Every day, timed again
+    All,
+    /// A single day, timed again
+    Day(u8),
+}
+
+impl Args {
+    fn mode(&self) -> Mode {
+        match (self.all, self.day) {
+            (_, Some(day)) => Mode::Day(day),
+            (true, None) => Mode::All,
+            (false, None) => Mode::Staged,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -25,62 +139,258 @@ struct Day {
     part_2: Option<Time>,
 }
 
+/// How far a day got, from which of its parts have a time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Solved,
+    Partial,
+    Unsolved,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Solved => "✅ solved",
+            Status::Partial => "🟡 partial",
+            Status::Unsolved => "❌ unsolved",
+        }
+    }
+}
+
+impl Day {
+    ///
+    /// # `status`
+    /// Derive the status of the day from its times: both parts timed means solved,
+    /// only one means partial.
+    ///
+    /// ## Returns
+    /// * `Status`: The status of the day.
+    fn status(&self) -> Status {
+        match (&self.part_1, &self.part_2) {
+            (Some(_), Some(_)) => Status::Solved,
+            (None, None) => Status::Unsolved,
+            _ => Status::Partial,
+        }
+    }
+}
+
 impl PartialEq for Day {
     fn eq(&self, other: &Self) -> bool {
         self.number == other.number
     }
 }
 
+/// Why a string isn't a `Time`
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TimeParseError {
+    Empty,
+    MissingNumber,
+    MissingUnit,
+    InvalidNumber(String),
+    UnknownUnit(String),
+}
+
+impl fmt::Display for TimeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeParseError::Empty => write!(f, "empty time"),
+            TimeParseError::MissingNumber => write!(f, "time without a number"),
+            TimeParseError::MissingUnit => write!(f, "time without a unit"),
+            TimeParseError::InvalidNumber(number) => write!(f, "invalid number `{number}`"),
+            TimeParseError::UnknownUnit(unit) => write!(f, "unknown unit `{unit}`"),
+        }
+    }
+}
+
 impl FromStr for Time {
-    type Err = ();
+    type Err = TimeParseError;
 
+    // Accepts both `.` and `,` as the decimal separator, e.g. `176.541µs` or `176,541µs`
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s == "" {
-            return Err(());
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(TimeParseError::Empty);
         }
 
-        let time_regex = Regex::new(r"(?P<value>\d+\.\d+)(?P<unit>\w+)").unwrap();
-        let captures = time_regex.captures(s).unwrap();
+        let unit_start = s
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+            .unwrap_or(s.len());
+        let (number, unit) = (&s[..unit_start], s[unit_start..].trim());
+
+        if number.is_empty() {
+            return Err(TimeParseError::MissingNumber);
+        }
+        if unit.is_empty() {
+            return Err(TimeParseError::MissingUnit);
+        }
+        if !["ns", "µs", "us", "ms", "s"].contains(&unit) {
+            return Err(TimeParseError::UnknownUnit(unit.to_string()));
+        }
 
         Ok(Time {
-            number: captures
-                .name("value")
-                .unwrap()
-                .as_str()
+            number: number
+                .replace(',', ".")
                 .parse::<f32>()
-                .unwrap(),
-            unit: captures.name("unit").unwrap().as_str().to_string(),
+                .map_err(|_| TimeParseError::InvalidNumber(number.to_string()))?,
+            unit: unit.to_string(),
         })
     }
 }
 
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:.2}{}", self.number, self.unit)
+    }
+}
+
 impl Time {
-    fn to_string(&self) -> String {
-        format!("{:.2}{}", self.number, self.unit)
+    ///
+    /// # `to_nanos`
+    /// Convert the time to nanoseconds, so that times in different units can be compared.
+    ///
+    /// ## Returns
+    /// * `f64`: The time in nanoseconds, `NaN` for an unknown unit.
+    fn to_nanos(&self) -> f64 {
+        let factor = match self.unit.as_str() {
+            "s" => 1e9,
+            "ms" => 1e6,
+            "µs" | "us" => 1e3,
+            "ns" => 1.0,
+            _ => f64::NAN,
+        };
+
+        f64::from(self.number) * factor
+    }
+
+    ///
+    /// # `from_nanos`
+    /// Build a time from nanoseconds, in the largest unit keeping the number above 1.
+    ///
+    /// ## Arguments
+    /// * `nanos`: The time in nanoseconds.
+    ///
+    /// ## Returns
+    /// * `Time`: The time in `s`, `ms`, `µs` or `ns`.
+    fn from_nanos(nanos: f64) -> Self {
+        let (factor, unit) = [(1e9, "s"), (1e6, "ms"), (1e3, "µs")]
+            .into_iter()
+            .find(|&(factor, _)| nanos >= factor)
+            .unwrap_or((1.0, "ns"));
+
+        Time {
+            number: (nanos / factor) as f32,
+            unit: unit.to_string(),
+        }
+    }
+}
+
+// Times are compared by duration, `1000.00µs` being equal to `1.00ms`
+impl PartialEq for Time {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Time {}
+
+impl PartialOrd for Time {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Time {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_nanos().total_cmp(&other.to_nanos())
     }
 }
 
 // Functions  =========================================================================== Functions
+///
+/// # `total_row`
+/// Build the README row summing the times of every day, days missing a part being skipped
+/// for that part.
+///
+/// ## Arguments
+/// * `days`: The days of the table.
+///
+/// ## Returns
+/// * `String`: The `| **Total** | ... | ... | |` row, ending with a newline.
+fn total_row(days: &[Day]) -> String {
+    let total = |part: fn(&Day) -> &Option<Time>| {
+        let nanos = days
+            .iter()
+            .filter_map(|day| part(day).as_ref())
+            .map(Time::to_nanos)
+            .fold(0.0, |total, nanos| total + nanos);
+
+        Time::from_nanos(nanos).to_string()
+    };
+
+    format!(
+        "| **Total** | {} | {} | |\n",
+        total(|day| &day.part_1),
+        total(|day| &day.part_2)
+    )
+}
+
+///
+/// # `find_repo_root`
+/// Walk up from `start` until finding the solutions crate: a directory with a
+/// `Cargo.toml` next to a `src/bin` directory.
+///
+/// ## Arguments
+/// * `start`: Directory to start searching from.
+///
+/// ## Returns
+/// * `Option<PathBuf>`: The repository root, if any ancestor of `start` is one.
+fn find_repo_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file() && dir.join("src/bin").is_dir())
+        .map(Path::to_path_buf)
+}
+
 ///
 /// # `git_staged_files_to_days`
 /// Get the staged files from git and return a vector of `Day` struct.
 ///
 /// ## Returns
-/// * `Vec<Day>`: Vector of `Day` struct.
-fn git_staged_files_to_days() -> Vec<Day> {
+/// * `Result<Vec<Day>, std::io::Error>`: Vector of `Day` struct, or an error if git can't be
+///   run or fails (e.g. outside of a repository).
+fn git_staged_files_to_days() -> Result<Vec<Day>, std::io::Error> {
     let git_command = Command::new("git")
         .arg("diff")
         .arg("--cached")
         .arg("--name-only")
-        .output()
-        .expect("Failed to execute git diff --cached --name-only");
+        .output()?;
+
+    if !git_command.status.success() {
+        return Err(std::io::Error::other(
+            String::from_utf8_lossy(&git_command.stderr)
+                .trim()
+                .to_string(),
+        ));
+    }
+
+    let git_output = String::from_utf8_lossy(&git_command.stdout);
 
-    let git_output =
-        String::from_utf8(git_command.stdout).expect("Failed to convert git output to string");
+    Ok(staged_files_to_days(&git_output))
+}
 
+///
+/// # `staged_files_to_days`
+/// Get the days from a list of file paths, one per line, ignoring the other files.
+///
+/// ## Arguments
+/// * `files`: Output of `git diff --cached --name-only`.
+///
+/// ## Returns
+/// * `Vec<Day>`: Vector of `Day` struct, without times.
+fn staged_files_to_days(files: &str) -> Vec<Day> {
     let day_regex = Regex::new(r"src/bin/day_(\d+)\.rs").unwrap();
 
-    git_output
+    files
         .lines()
         .filter_map(|line| day_regex.captures(line))
         .map(|captures| {
@@ -95,6 +405,34 @@ fn git_staged_files_to_days() -> Vec<Day> {
         .collect()
 }
 
+///
+/// # `all_days_in_bin`
+/// Get every day with a solution in the `src/bin` directory.
+///
+/// ## Returns
+/// * `Vec<Day>`: Vector of `Day` struct, without times.
+fn all_days_in_bin() -> Vec<Day> {
+    let entries = std::fs::read_dir("src/bin").unwrap();
+    let day_regex = Regex::new(r"day_(\d+)\.rs").unwrap();
+
+    entries
+        .filter_map(|entry| {
+            let entry = entry.unwrap();
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if let Some(captures) = day_regex.captures(&file_name) {
+                let day_number = captures.get(1).unwrap().as_str().parse::<u8>().unwrap();
+                Some(Day {
+                    number: day_number,
+                    part_1: None,
+                    part_2: None,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
 ///
 /// # `get_existing_days_in_readme`
 /// Get the existing days in the README.md file.
@@ -110,9 +448,7 @@ fn get_existing_days_in_readme() -> Vec<Day> {
 
     let readme_content = std::fs::read_to_string(readme_path).unwrap();
 
-    let day_regex =
-        Regex::new(r"\| \[Day (?P<day_number>\d+)\]\(src/bin/day_(?:\d+)\.rs\) \| (?P<part_1>.*?) \| (?P<part_2>.*?)? \|")
-            .unwrap();
+    let day_regex = Regex::new(DAY_ROW_PATTERN).unwrap();
 
     day_regex
         .captures_iter(&readme_content)
@@ -142,46 +478,449 @@ fn get_existing_days_in_readme() -> Vec<Day> {
 }
 
 ///
-/// # `time_execution`
-/// Time the execution of a day.
+/// # `parse_durations`
+/// Parse the `Duration:` lines printed by a day, in order.
 ///
 /// ## Arguments
-/// * `day`: `Day` struct.
+/// * `output`: Standard output of the day's binary.
 ///
 /// ## Returns
-/// * `Day`: `Day` struct with the execution time.
-fn time_execution(day: Day) -> Day {
-    let day_path = format!("day_{:02}", day.number);
+/// * `Result<Vec<Time>, TimeParseError>`: One time per `Duration:` line, or the error of the
+///   first one that isn't a valid time.
+fn parse_durations(output: &str) -> Result<Vec<Time>, TimeParseError> {
+    let duration_regex = Regex::new(r"Duration: (?P<time>\S+)").unwrap();
+
+    duration_regex
+        .captures_iter(output)
+        .map(|captures| Time::from_str(&captures["time"]))
+        .collect()
+}
+
+///
+/// # `parse_answers`
+/// Parse the answer lines printed by a day: every `label: value` line but the durations,
+/// e.g. `Result: 42` or `The sum of all multiplications is: 161`.
+///
+/// ## Arguments
+/// * `output`: Standard output of the day's binary.
+///
+/// ## Returns
+/// * `Vec<String>`: The answers, in order.
+fn parse_answers(output: &str) -> Vec<String> {
+    let answer_regex = Regex::new(r"^(?P<label>[^:]+): (?P<answer>.+)$").unwrap();
 
+    output
+        .lines()
+        .filter_map(|line| answer_regex.captures(line.trim_end()))
+        .filter(|captures| &captures["label"] != "Duration")
+        .map(|captures| captures["answer"].to_string())
+        .collect()
+}
+
+///
+/// # `parse_answers_file`
+/// Parse the stored answers, one `day_XX = ["answer", ...]` line per day.
+///
+/// ## Arguments
+/// * `content`: Content of the answers file.
+///
+/// ## Returns
+/// * `BTreeMap<u8, Vec<String>>`: The answers of each day.
+fn parse_answers_file(content: &str) -> BTreeMap<u8, Vec<String>> {
+    let line_regex = Regex::new(r"^day_(?P<day_number>\d+)\s*=\s*\[(?P<answers>.*)\]$").unwrap();
+    let answer_regex = Regex::new(r#""(?P<answer>(?:[^"\\]|\\.)*)""#).unwrap();
+
+    content
+        .lines()
+        .filter_map(|line| line_regex.captures(line.trim()))
+        .filter_map(|captures| {
+            let day_number = captures["day_number"].parse::<u8>().ok()?;
+            let answers = answer_regex
+                .captures_iter(&captures["answers"])
+                .map(|answer| answer["answer"].replace("\\\"", "\"").replace("\\\\", "\\"))
+                .collect();
+
+            Some((day_number, answers))
+        })
+        .collect()
+}
+
+///
+/// # `render_answers_file`
+/// Render the stored answers, the reverse of `parse_answers_file`.
+///
+/// ## Arguments
+/// * `answers`: The answers of each day.
+///
+/// ## Returns
+/// * `String`: Content of the answers file.
+fn render_answers_file(answers: &BTreeMap<u8, Vec<String>>) -> String {
+    answers
+        .iter()
+        .map(|(day_number, answers)| {
+            let answers = answers
+                .iter()
+                .map(|answer| format!("{answer:?}"))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            format!("day_{day_number:02} = [{answers}]\n")
+        })
+        .collect()
+}
+
+///
+/// # `median_time`
+/// Get the median of several measurements of the same part.
+///
+/// ## Arguments
+/// * `times`: The measurements.
+///
+/// ## Returns
+/// * `Option<Time>`: The median (the upper one for an even count), `None` without measurements.
+fn median_time(mut times: Vec<Time>) -> Option<Time> {
+    times.sort();
+    let middle = times.len() / 2;
+
+    times.into_iter().nth(middle)
+}
+
+///
+/// # `run_day`
+/// Run a day's binary in release mode.
+///
+/// ## Arguments
+/// * `day_path`: Name of the binary, e.g. `day_01`.
+///
+/// ## Returns
+/// * `String`: Standard output of the binary.
+fn run_day(day_path: &str) -> String {
     let bin_run_command = Command::new("cargo")
         .arg("run")
         .arg("--release")
         .arg("--bin")
-        .arg(&day_path)
+        .arg(day_path)
         .output()
         .expect("Failed to execute cargo run");
 
-    let time_output = String::from_utf8(bin_run_command.stdout).unwrap();
+    String::from_utf8(bin_run_command.stdout).unwrap()
+}
 
-    let time_regex = Regex::new(r"Duration: (?P<value>\d+\.\d+)(?P<unit>\w+)").unwrap();
-    let times = time_regex
-        .captures_iter(&time_output)
-        .map(|captures| Time {
-            number: captures
-                .name("value")
-                .unwrap()
-                .as_str()
-                .parse::<f32>()
-                .unwrap(),
-            unit: captures.name("unit").unwrap().as_str().to_string(),
-        })
-        .collect::<Vec<Time>>();
+///
+/// # `time_execution`
+/// Time the execution of a day, keeping the median of several runs for each part.
+///
+/// ## Arguments
+/// * `day`: `Day` struct.
+/// * `warmup`: Number of runs before the measured ones, whose output is ignored.
+/// * `runs`: Number of measured runs.
+/// * `answers`: Stored answers of each day, the day's answers are added if missing.
+///
+/// ## Returns
+/// * `Option<Day>`: See `time_execution_with`.
+fn time_execution(
+    day: Day,
+    warmup: usize,
+    runs: usize,
+    answers: &mut BTreeMap<u8, Vec<String>>,
+) -> Option<Day> {
+    time_execution_with(day, warmup, runs, answers, run_day)
+}
+
+///
+/// # `time_execution_with`
+/// Time the execution of a day with the given runner, keeping the median of the measured
+/// runs for each part.
+///
+/// ## Arguments
+/// * `day`: `Day` struct.
+/// * `warmup`: Number of runs before the measured ones, whose output is ignored.
+/// * `runs`: Number of measured runs.
+/// * `answers`: Stored answers of each day, the day's answers are added if missing.
+/// * `runner`: Runs the binary named after the day and returns its standard output.
+///
+/// ## Returns
+/// * `Option<Day>`: `Day` struct with the execution time, unchanged if the day printed no
+///   duration. `None` if the day printed an invalid duration or if its answers differ
+///   from the stored ones.
+fn time_execution_with(
+    day: Day,
+    warmup: usize,
+    runs: usize,
+    answers: &mut BTreeMap<u8, Vec<String>>,
+    mut runner: impl FnMut(&str) -> String,
+) -> Option<Day> {
+    let day_path = format!("day_{:02}", day.number);
+
+    let mut part_1_times = Vec::new();
+    let mut part_2_times = Vec::new();
+    let mut day_answers = Vec::new();
+
+    for _ in 0..warmup {
+        runner(&day_path);
+    }
+
+    for _ in 0..runs {
+        let time_output = runner(&day_path);
+        let mut times = match parse_durations(&time_output) {
+            Ok(times) => times.into_iter(),
+            Err(error) => {
+                eprintln!(
+                    "Warning: day {} printed a bad duration ({error})",
+                    day.number
+                );
+                return None;
+            }
+        };
+        day_answers = parse_answers(&time_output);
+
+        part_1_times.extend(times.next());
+        part_2_times.extend(times.next());
+    }
+
+    match answers.get(&day.number) {
+        Some(stored) if *stored != day_answers => {
+            eprintln!(
+                "Warning: day {} answered {:?} instead of {:?}, its timing is not updated",
+                day.number, day_answers, stored
+            );
+            return None;
+        }
+        None if !day_answers.is_empty() => {
+            answers.insert(day.number, day_answers);
+        }
+        _ => {}
+    }
 
-    Day {
+    if part_1_times.is_empty() {
+        return Some(day);
+    }
+
+    Some(Day {
         number: day.number,
-        part_1: times.first().cloned(),
-        part_2: times.get(1).cloned(),
+        part_1: median_time(part_1_times),
+        part_2: median_time(part_2_times),
+    })
+}
+
+///
+/// # `sort_days`
+/// Sort the rows of the table.
+///
+/// ## Arguments
+/// * `days`: The days to sort.
+/// * `order`: By number, or by total time of both parts with days missing a part last
+///   (those by number).
+fn sort_days(days: &mut [Day], order: SortOrder) {
+    match order {
+        SortOrder::Day => days.sort_by_key(|day| day.number),
+        SortOrder::Speed => days.sort_by(|a, b| {
+            let total = |day: &Day| match (&day.part_1, &day.part_2) {
+                (Some(part_1), Some(part_2)) => part_1.to_nanos() + part_2.to_nanos(),
+                _ => f64::INFINITY,
+            };
+
+            total(a).total_cmp(&total(b)).then(a.number.cmp(&b.number))
+        }),
+    }
+}
+
+///
+/// # `render_table`
+/// Render the solutions table: header, one row per day with its status and the total row.
+///
+/// ## Arguments
+/// * `days`: The days, in display order.
+///
+/// ## Returns
+/// * `String`: The markdown table, ending with a newline.
+fn render_table(days: &[Day]) -> String {
+    let mut table =
+        String::from("| Day | Part 1 | Part 2 | Status |\n|-----|--------|--------|--------|\n");
+
+    for day in days {
+        table.push_str(&format!(
+            "| [Day {}](src/bin/day_{:02}.rs) | {} | {} | {} |\n",
+            day.number,
+            day.number,
+            day.part_1
+                .as_ref()
+                .map_or("".to_string(), |time| time.to_string()),
+            day.part_2
+                .as_ref()
+                .map_or("".to_string(), |time| time.to_string()),
+            day.status().label()
+        ));
+    }
+    table.push_str(&total_row(days));
+
+    table
+}
+
+///
+/// # `replace_table`
+/// Replace the solutions table of a README, keeping everything else as is.
+/// The table is found between the `TABLE_START`/`TABLE_END` markers, or else by its
+/// `| Day | Part 1 | Part 2 |` header (the markers are then added around it).
+/// Without either, the table is appended under a new `## Solutions` section.
+///
+/// ## Arguments
+/// * `readme`: The current README content.
+/// * `table`: The new table, as returned by `render_table`.
+///
+/// ## Returns
+/// * `String`: The updated README content.
+fn replace_table(readme: &str, table: &str) -> String {
+    let block = format!("{TABLE_START}\n{table}{TABLE_END}");
+
+    if let (Some(start), Some(end)) = (readme.find(TABLE_START), readme.find(TABLE_END)) {
+        if start < end {
+            return format!(
+                "{}{block}{}",
+                &readme[..start],
+                &readme[end + TABLE_END.len()..]
+            );
+        }
+    }
+
+    if let Some(start) = readme.find("| Day | Part 1 | Part 2 |") {
+        // The table runs until the first line that isn't a table row
+        let table_len: usize = readme[start..]
+            .split_inclusive('\n')
+            .take_while(|line| line.starts_with('|'))
+            .map(str::len)
+            .sum();
+        let rest = &readme[start + table_len..];
+        let newline = if rest.is_empty() { "" } else { "\n" };
+
+        return format!("{}{block}{newline}{rest}", &readme[..start]);
     }
+
+    format!("{}\n\n## Solutions\n{block}\n", readme.trim_end())
+}
+
+///
+/// # `line_diff`
+/// Diff two texts line by line, through their longest common subsequence of lines.
+///
+/// ## Arguments
+/// * `old`: The original text.
+/// * `new`: The updated text.
+///
+/// ## Returns
+/// * `Vec<(char, &str)>`: Every line prefixed by `' '` if kept, `'-'` if removed or `'+'` if added.
+fn line_diff<'a>(old: &'a str, new: &'a str) -> Vec<(char, &'a str)> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j]: length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push(('-', old[i]));
+            i += 1;
+        } else {
+            diff.push(('+', new[j]));
+            j += 1;
+        }
+    }
+
+    diff
+}
+
+///
+/// # `unified_diff`
+/// Format the changes between two texts as a unified diff with 3 lines of context.
+///
+/// ## Arguments
+/// * `old`: The original text.
+/// * `new`: The updated text.
+/// * `name`: Name of the file, for the `---`/`+++` headers.
+///
+/// ## Returns
+/// * `String`: The diff, empty if the texts have the same lines.
+fn unified_diff(old: &str, new: &str, name: &str) -> String {
+    const CONTEXT: usize = 3;
+
+    let diff = line_diff(old, new);
+    let changes: Vec<usize> = (0..diff.len()).filter(|&i| diff[i].0 != ' ').collect();
+
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    // Group the changes closer than twice the context into hunks of diff indices
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &change in &changes {
+        let start = change.saturating_sub(CONTEXT);
+        let end = (change + CONTEXT + 1).min(diff.len());
+
+        match hunks.last_mut() {
+            Some(hunk) if start <= hunk.1 => hunk.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut output = format!("--- {name}\n+++ {name}\n");
+    for (start, end) in hunks {
+        let old_line = 1 + diff[..start].iter().filter(|(op, _)| *op != '+').count();
+        let new_line = 1 + diff[..start].iter().filter(|(op, _)| *op != '-').count();
+        let old_len = diff[start..end].iter().filter(|(op, _)| *op != '+').count();
+        let new_len = diff[start..end].iter().filter(|(op, _)| *op != '-').count();
+
+        output.push_str(&format!(
+            "@@ -{old_line},{old_len} +{new_line},{new_len} @@\n"
+        ));
+        for (op, line) in &diff[start..end] {
+            output.push_str(&format!("{op}{line}\n"));
+        }
+    }
+
+    output
+}
+
+///
+/// # `write_readme`
+/// Write the new README content, or only print how it differs from the current one.
+///
+/// ## Arguments
+/// * `path`: Path to the README.
+/// * `content`: The new README content.
+/// * `dry_run`: Print a diff against the current file instead of writing it.
+///
+/// ## Returns
+/// * `String`: The new content, whether it was written or not.
+fn write_readme(path: &Path, content: String, dry_run: bool) -> String {
+    if dry_run {
+        let current = std::fs::read_to_string(path).unwrap_or_default();
+        let diff = unified_diff(&current, &content, &path.display().to_string());
+
+        if diff.is_empty() {
+            println!("{} is up to date", path.display());
+        } else {
+            print!("{diff}");
+        }
+    } else {
+        std::fs::write(path, &content).unwrap();
+    }
+
+    content
 }
 
 ///
@@ -189,39 +928,41 @@ fn time_execution(day: Day) -> Day {
 /// Update the README.md file with the new days.
 ///
 /// ## Arguments
-/// * `update_all`: Update all days instead of only les.
-fn update_readme(update_all: bool) {
+/// * `mode`: Which days to time.
+/// * `sort`: Order of the days in the table.
+/// * `warmup`: Number of runs per day before the measured ones, whose output is ignored.
+/// * `runs`: Number of measured runs per day.
+/// * `dry_run`: Print a diff against the current README instead of writing it.
+fn update_readme(mode: Mode, sort: SortOrder, warmup: usize, runs: usize, dry_run: bool) {
     let readme_path = Path::new("README.md");
 
+    let answers_path = Path::new(ANSWERS_PATH);
+
     let existing_days = get_existing_days_in_readme();
-    let days_to_process = if update_all {
-        // Get all days from the src/bin directory
-        let entries = std::fs::read_dir("src/bin").unwrap();
-        let day_regex = Regex::new(r"day_(\d+)\.rs").unwrap();
-
-        entries
-            .filter_map(|entry| {
-                let entry = entry.unwrap();
-                let file_name = entry.file_name().to_string_lossy().to_string();
-                if let Some(captures) = day_regex.captures(&file_name) {
-                    let day_number = captures.get(1).unwrap().as_str().parse::<u8>().unwrap();
-                    Some(Day {
-                        number: day_number,
-                        part_1: None,
-                        part_2: None,
-                    })
-                } else {
-                    None
-                }
-            })
-            .collect()
-    } else {
-        git_staged_files_to_days()
+    let mut answers = std::fs::read_to_string(answers_path)
+        .map(|content| parse_answers_file(&content))
+        .unwrap_or_default();
+    let days_to_process = match mode {
+        Mode::All => all_days_in_bin(),
+        Mode::Day(number) => vec![Day {
+            number,
+            part_1: None,
+            part_2: None,
+        }],
+        Mode::Staged => git_staged_files_to_days().unwrap_or_else(|error| {
+            eprintln!("Could not read the staged files ({error}), scanning src/bin instead");
+            all_days_in_bin()
+        }),
     };
 
     let days_to_update = days_to_process
         .iter()
         .filter(|staged_day| {
+            // Explicitly requested days are always timed again
+            if mode != Mode::Staged {
+                return true;
+            }
+
             // Get corresponding existing day if it exists
             if let Some(existing_day) = existing_days.iter().find(|d| d.number == staged_day.number)
             {
@@ -232,9 +973,16 @@ fn update_readme(update_all: bool) {
                 true
             }
         })
-        .map(|day| time_execution(day.clone()))
+        .filter_map(|day| time_execution(day.clone(), warmup, runs, &mut answers))
         .collect::<Vec<Day>>();
 
+    if !dry_run && !answers.is_empty() {
+        if let Some(parent) = answers_path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+        std::fs::write(answers_path, render_answers_file(&answers)).unwrap();
+    }
+
     let mut final_days: Vec<Day> = existing_days
         .iter()
         .map(|existing_day| {
@@ -257,86 +1005,31 @@ fn update_readme(update_all: bool) {
         )
         .collect();
 
-    final_days.sort_by(|a, b| a.number.cmp(&b.number));
+    sort_days(&mut final_days, sort);
 
     if days_to_update.is_empty() {
         return;
     }
 
-    let mut new_content = String::from("# Advent of Code 2024
-```
-        .
-\\_____)\\_____
-/--v____ __`< My Rust solutions to the Advent of Code 2024 challenges
-    )/
-    '
-```
-
-## Overview
-This repository contains my solutions to the [Advent of Code 2024](https://adventofcode.com/2024) challenges, implemented in Rust.
-
-## Project Structure
-- `src/bin/`: Contains the daily challenge solutions
-- `src/lib.rs`: Common utilities and helper functions
-- `src/points.rs`: Point-related utilities for geometric calculations
-- `data/inputs/`: Input files for each day's challenge (not included in repository)
-
-## Solutions
-| Day | Part 1 | Part 2 |
-|-----|--------|--------|\n");
-
-    let final_days_content = final_days
-        .iter()
-        .map(|day| {
-            format!(
-                "| [Day {}](src/bin/day_{:02}.rs) | {} | {} |\n",
-                day.number,
-                day.number,
-                day.part_1
-                    .as_ref()
-                    .map_or("".to_string(), |time| time.to_string()),
-                day.part_2
-                    .as_ref()
-                    .map_or("".to_string(), |time| time.to_string())
-            )
-        })
-        .collect::<String>();
+    let readme =
+        std::fs::read_to_string(readme_path).unwrap_or_else(|_| DEFAULT_README.to_string());
+    let new_content = replace_table(&readme, &render_table(&final_days));
 
-    new_content.push_str(&final_days_content);
-
-    new_content.push_str(
-        "\n
-## Running the Solutions
-
-To run a specific day's solution:
-```bash
-cargo run --release --bin day_01
-```
-
-To run a specific day's solution tests:
-```bash
-cargo test --bin day_01
-```
-
-To run all tests:
-```bash
-cargo test
-```
-
-
-## License
-This project is open source and available under the MIT License.",
-    );
-
-    std::fs::write(readme_path, new_content).unwrap();
+    write_readme(readme_path, new_content, dry_run);
 }
 
 fn main() {
-    let current = Path::new("/Users/tom_planche/Desktop/Prog/Rust/all_aoc/aoc_2024");
-    std::env::set_current_dir(current).unwrap();
-
     let args = Args::parse();
-    update_readme(args.all);
+
+    let root = args.root.clone().unwrap_or_else(|| {
+        find_repo_root(Path::new(env!("CARGO_MANIFEST_DIR")))
+            .or_else(|| find_repo_root(&std::env::current_dir().unwrap()))
+            .unwrap_or_else(|| PathBuf::from("."))
+    });
+    std::env::set_current_dir(&root)
+        .unwrap_or_else(|_| panic!("Failed to move to the repository root {}", root.display()));
+
+    update_readme(args.mode(), args.sort, args.warmup, args.runs, args.dry_run);
 }
 
 // Tests
@@ -361,4 +1054,375 @@ mod tests {
         assert_eq!(time.number, 176.541);
         assert_eq!(time.unit, "µs");
     }
+
+    #[test]
+    fn test_find_repo_root() {
+        let tmp = std::env::temp_dir().join(format!("update_aoc_readme_{}", std::process::id()));
+        let root = tmp.join("aoc");
+        let nested = root.join("update_aoc_readme/src");
+        std::fs::create_dir_all(root.join("src/bin")).unwrap();
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(root.join("Cargo.toml"), "[package]").unwrap();
+        // The tool's own crate has a manifest but no `src/bin`, it must be skipped
+        std::fs::write(root.join("update_aoc_readme/Cargo.toml"), "[package]").unwrap();
+
+        assert_eq!(find_repo_root(&nested), Some(root.clone()));
+        assert_eq!(find_repo_root(&root), Some(root.clone()));
+        assert_eq!(find_repo_root(&tmp), None);
+
+        std::fs::remove_dir_all(&tmp).unwrap();
+    }
+
+    #[test]
+    fn test_time_ordering() {
+        let millisecond = Time {
+            number: 1.0,
+            unit: "ms".to_string(),
+        };
+        let half_millisecond = Time {
+            number: 500.0,
+            unit: "µs".to_string(),
+        };
+        let half_millisecond_ascii = Time {
+            number: 500.0,
+            unit: "us".to_string(),
+        };
+
+        assert!(millisecond > half_millisecond);
+        assert_eq!(half_millisecond, half_millisecond_ascii);
+
+        let mut times = [millisecond.clone(), half_millisecond.clone()];
+        times.sort();
+        assert_eq!(times[0].unit, "µs");
+
+        let time: Time = "176.541µs".parse().unwrap();
+        assert!((time.to_nanos() - 176_541.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_total_row() {
+        let time = |s: &str| Time::from_str(s).ok();
+        let days = vec![
+            Day {
+                number: 1,
+                part_1: time("1.50ms"),
+                part_2: time("500.00µs"),
+            },
+            Day {
+                number: 2,
+                part_1: time("250.00µs"),
+                part_2: None,
+            },
+            Day {
+                number: 3,
+                part_1: time("0.75s"),
+                part_2: time("20.00ns"),
+            },
+        ];
+
+        assert_eq!(total_row(&days), "| **Total** | 751.75ms | 500.02µs | |\n");
+        assert_eq!(total_row(&[]), "| **Total** | 0.00ns | 0.00ns | |\n");
+    }
+
+    #[test]
+    fn test_parse_durations() {
+        let output = "Day 01 - Part 1\nDuration: 1.5ms\nDay 01 - Part 2\nDuration: 176.541µs\n";
+        let times = parse_durations(output).unwrap();
+
+        assert_eq!(times.len(), 2);
+        assert_eq!(times[0].unit, "ms");
+        assert_eq!(times[1].number, 176.541);
+        assert!(parse_durations("no timing here").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_median_time() {
+        let times = ["2.00ms", "900.00µs", "1.50ms", "3.00s", "1.20ms"]
+            .iter()
+            .map(|s| s.parse().unwrap())
+            .collect::<Vec<Time>>();
+
+        let median = median_time(times).unwrap();
+        assert_eq!(median.number, 1.5);
+        assert_eq!(median.unit, "ms");
+
+        assert!(median_time(vec![]).is_none());
+    }
+
+    #[test]
+    fn test_staged_files_to_days() {
+        let days =
+            staged_files_to_days("README.md\nsrc/bin/day_07.rs\nsrc/lib.rs\nsrc/bin/day_18.rs\n");
+
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].number, 7);
+        assert_eq!(days[1].number, 18);
+        assert!(days[0].part_1.is_none());
+
+        assert!(staged_files_to_days("").is_empty());
+    }
+
+    #[test]
+    fn test_args_mode() {
+        let parse = |args: &[&str]| {
+            Args::try_parse_from(std::iter::once("update_aoc_readme").chain(args.iter().copied()))
+        };
+
+        assert_eq!(parse(&["--day", "18"]).unwrap().mode(), Mode::Day(18));
+        assert_eq!(parse(&["--all"]).unwrap().mode(), Mode::All);
+        assert_eq!(parse(&[]).unwrap().mode(), Mode::Staged);
+        assert!(parse(&["--all", "--day", "18"]).is_err());
+    }
+
+    #[test]
+    fn test_time_from_str_units() {
+        for (input, number, unit) in [
+            ("5s", 5.0, "s"),
+            ("0ns", 0.0, "ns"),
+            ("176µs", 176.0, "µs"),
+            ("12us", 12.0, "us"),
+            ("2.5ms", 2.5, "ms"),
+        ] {
+            let time: Time = input.parse().unwrap();
+            assert_eq!(time.number, number, "{input}");
+            assert_eq!(time.unit, unit, "{input}");
+        }
+
+        assert!("fast".parse::<Time>().is_err());
+        assert_eq!(
+            parse_durations("Duration: 5s\nDuration: 0ns")
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_replace_table_keeps_prose() {
+        let days = vec![Day {
+            number: 1,
+            part_1: Time::from_str("1.00ms").ok(),
+            part_2: None,
+        }];
+        let readme = "# My AoC\n\nSome notes I wrote.\n\n## Solutions\n| Day | Part 1 | Part 2 |\n|-----|--------|--------|\n| [Day 1](src/bin/day_01.rs) | 2.00ms |  |\n\n## Custom section\nKeep me.\n";
+
+        let updated = replace_table(readme, &render_table(&days));
+
+        assert!(updated.starts_with(
+            "# My AoC\n\nSome notes I wrote.\n\n## Solutions\n<!-- aoc:table:start -->\n"
+        ));
+        assert!(updated.ends_with("<!-- aoc:table:end -->\n\n## Custom section\nKeep me.\n"));
+        assert!(updated.contains("| [Day 1](src/bin/day_01.rs) | 1.00ms |  | 🟡 partial |\n"));
+        assert!(!updated.contains("2.00ms"));
+
+        // Once the markers are there, regenerating only touches what is between them
+        assert_eq!(replace_table(&updated, &render_table(&days)), updated);
+    }
+
+    #[test]
+    fn test_replace_table_without_table() {
+        let updated = replace_table("# Title\n", "| Day | Part 1 | Part 2 |\n");
+
+        assert_eq!(
+            updated,
+            "# Title\n\n## Solutions\n<!-- aoc:table:start -->\n| Day | Part 1 | Part 2 |\n<!-- aoc:table:end -->\n"
+        );
+        assert!(DEFAULT_README.contains(TABLE_START));
+    }
+
+    #[test]
+    fn test_day_status() {
+        let time = |s: &str| Time::from_str(s).ok();
+        let days = vec![
+            Day {
+                number: 1,
+                part_1: time("1.00ms"),
+                part_2: time("2.00ms"),
+            },
+            Day {
+                number: 18,
+                part_1: time("3.00ms"),
+                part_2: None,
+            },
+            Day {
+                number: 25,
+                part_1: None,
+                part_2: None,
+            },
+        ];
+
+        let labels: Vec<&str> = days.iter().map(|day| day.status().label()).collect();
+
+        assert_eq!(labels, vec!["✅ solved", "🟡 partial", "❌ unsolved"]);
+        assert!(render_table(&days)
+            .contains("| [Day 18](src/bin/day_18.rs) | 3.00ms |  | 🟡 partial |\n"));
+    }
+
+    #[test]
+    fn test_existing_row_with_status() {
+        let row = "| [Day 18](src/bin/day_18.rs) | 3.00ms |  | 🟡 partial |";
+        let day_regex = Regex::new(DAY_ROW_PATTERN).unwrap();
+        let captures = day_regex.captures(row).unwrap();
+
+        assert_eq!(&captures["part_1"], "3.00ms");
+        assert_eq!(&captures["part_2"], "");
+    }
+
+    #[test]
+    fn test_time_display() {
+        let time = Time {
+            number: 176.541,
+            unit: "µs".to_string(),
+        };
+
+        assert_eq!(format!("{}", time), "176.54µs");
+        assert_eq!(time.to_string(), "176.54µs");
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let output = "Day 03 - Part 1\nThe sum of all multiplications is: 161\nDuration: 176.541µs\n\nDay 03 - Part 2\nThe sum of all enabled multiplications is: 48\nDuration: 1.2ms\n";
+
+        assert_eq!(parse_answers(output), vec!["161", "48"]);
+        assert_eq!(
+            parse_durations(output),
+            Ok(vec![
+                Time::from_str("176.541µs").unwrap(),
+                Time::from_str("1.2ms").unwrap()
+            ])
+        );
+
+        let output = "Day 19 - Part 1\nCount: 6\nDuration: 1.5ms\n\nDay 19 - Part 2\nCount: 16\nDuration: 2ms\n";
+
+        assert_eq!(parse_answers(output), vec!["6", "16"]);
+        assert_eq!(parse_durations(output).unwrap().len(), 2);
+
+        // Extra information not written as `label: value` isn't an answer
+        let output = "Day 02 - Part 1\nCount: 2\n(1 increasing, 1 decreasing)\nDuration: 1ms\n";
+        assert_eq!(parse_answers(output), vec!["2"]);
+    }
+
+    #[test]
+    fn test_answers_file_round_trip() {
+        let answers = BTreeMap::from([
+            (1, vec!["11".to_string(), "31".to_string()]),
+            (18, vec!["22".to_string(), "6,1".to_string()]),
+            (21, vec!["say \"hi\"".to_string()]),
+        ]);
+
+        let content = render_answers_file(&answers);
+
+        assert!(content.starts_with("day_01 = [\"11\", \"31\"]\n"));
+        assert_eq!(parse_answers_file(&content), answers);
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let new = "a\nb\nc\nd\nE\nf\ng\nh\ni\n";
+
+        assert_eq!(
+            unified_diff(old, new, "README.md"),
+            "--- README.md\n+++ README.md\n@@ -2,7 +2,8 @@\n b\n c\n d\n-e\n+E\n f\n g\n h\n+i\n"
+        );
+        assert_eq!(unified_diff(old, old, "README.md"), "");
+    }
+
+    #[test]
+    fn test_write_readme_dry_run() {
+        let path =
+            std::env::temp_dir().join(format!("update_aoc_readme_{}.md", std::process::id()));
+        std::fs::write(&path, "# Old\n").unwrap();
+
+        let content = write_readme(&path, "# New\n".to_string(), true);
+
+        assert_eq!(content, "# New\n");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# Old\n");
+
+        write_readme(&path, content, false);
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "# New\n");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_time_from_str_errors() {
+        assert_eq!("176.54".parse::<Time>(), Err(TimeParseError::MissingUnit));
+        assert_eq!("ms".parse::<Time>(), Err(TimeParseError::MissingNumber));
+        assert_eq!("".parse::<Time>(), Err(TimeParseError::Empty));
+        assert_eq!(
+            "1.2.3ms".parse::<Time>(),
+            Err(TimeParseError::InvalidNumber("1.2.3".to_string()))
+        );
+        assert_eq!(
+            "5min".parse::<Time>(),
+            Err(TimeParseError::UnknownUnit("min".to_string()))
+        );
+        assert_eq!(
+            parse_durations("Duration: 12"),
+            Err(TimeParseError::MissingUnit)
+        );
+
+        let time: Time = "176,541µs".parse().unwrap();
+        assert_eq!(time.number, 176.541);
+    }
+
+    #[test]
+    fn test_time_execution_warmup() {
+        let day = Day {
+            number: 7,
+            part_1: None,
+            part_2: None,
+        };
+        let mut answers = BTreeMap::new();
+        let mut calls = 0;
+
+        let timed = time_execution_with(day, 2, 3, &mut answers, |day_path| {
+            assert_eq!(day_path, "day_07");
+            calls += 1;
+
+            // The warmup runs are slow, they must not be recorded
+            if calls <= 2 {
+                "Result: 3749\nDuration: 9s\nResult: 11387\nDuration: 9s\n".to_string()
+            } else {
+                "Result: 3749\nDuration: 1ms\nResult: 11387\nDuration: 2ms\n".to_string()
+            }
+        })
+        .unwrap();
+
+        assert_eq!(calls, 2 + 3);
+        assert_eq!(timed.part_1, Time::from_str("1ms").ok());
+        assert_eq!(timed.part_2, Time::from_str("2ms").ok());
+        assert_eq!(answers[&7], vec!["3749", "11387"]);
+    }
+
+    #[test]
+    fn test_sort_days() {
+        let time = |s: &str| Time::from_str(s).ok();
+        let days = vec![
+            Day {
+                number: 1,
+                part_1: time("2.00ms"),
+                part_2: time("1.00ms"),
+            },
+            Day {
+                number: 2,
+                part_1: time("500.00µs"),
+                part_2: None,
+            },
+            Day {
+                number: 3,
+                part_1: time("1.00ms"),
+                part_2: time("900.00µs"),
+            },
+        ];
+        let numbers = |days: &[Day]| days.iter().map(|day| day.number).collect::<Vec<u8>>();
+
+        let mut by_speed = days.clone();
+        sort_days(&mut by_speed, SortOrder::Speed);
+        assert_eq!(numbers(&by_speed), vec![3, 1, 2]);
+
+        sort_days(&mut by_speed, SortOrder::Day);
+        assert_eq!(numbers(&by_speed), numbers(&days));
+    }
 }